@@ -180,7 +180,7 @@ fn key_expansion(key: Key) -> Bytes176 {
     key_ex
 }
 
-fn aes128_encrypt_block(k: Key, input: Block) -> Block {
+pub(crate) fn aes128_encrypt_block(k: Key, input: Block) -> Block {
     let key_ex = key_expansion(k);
     block_cipher(input, key_ex)
 }
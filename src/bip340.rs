@@ -0,0 +1,162 @@
+// Import hacspec and all needed definitions.
+use hacspec::prelude::*;
+
+// Import primitives
+use crate::secp256k1::{self, Affine, FieldElement, Secp256k1Scalar};
+use crate::sha2;
+
+// Schnorr signatures for secp256k1 from BIP-340.
+// Public keys are x-only: a point is represented by its x-coordinate and the
+// point with even y is implied.
+
+bytes!(SecretKey, 32);
+bytes!(PublicKey, 32);
+bytes!(AuxRand, 32);
+bytes!(Signature, 64);
+
+// Tags for the tagged hashes (ASCII)
+// "BIP0340/aux"
+const TAG_AUX: &str = "424950303334302f617578";
+// "BIP0340/nonce"
+const TAG_NONCE: &str = "424950303334302f6e6f6e6365";
+// "BIP0340/challenge"
+const TAG_CHALLENGE: &str = "424950303334302f6368616c6c656e6765";
+
+/// hash_tag(x) = SHA256(SHA256(tag) || SHA256(tag) || x)
+pub fn tagged_hash(tag: ByteSeq, msg: ByteSeq) -> sha2::Digest {
+    let tag_hash = sha2::hash(tag);
+    let mut h_in = ByteSeq::new(64 + msg.len());
+    h_in = h_in.update(0, tag_hash);
+    h_in = h_in.update(32, tag_hash);
+    h_in = h_in.update(64, msg);
+    sha2::hash(h_in)
+}
+
+fn field_element_bytes(x: FieldElement) -> ByteSeq {
+    let b = x.to_byte_seq_be();
+    ByteSeq::new(32).update(32 - b.len(), b)
+}
+
+// bytes(P): the 32 byte encoding of the x-coordinate of P
+fn point_bytes(p: Affine) -> PublicKey {
+    PublicKey::from(field_element_bytes(p.0))
+}
+
+fn scalar_bytes(x: Secp256k1Scalar) -> ByteSeq {
+    let b = x.to_byte_seq_be();
+    ByteSeq::new(32).update(32 - b.len(), b)
+}
+
+fn to_canvas(x: Secp256k1Scalar) -> secp256k1::Scalar {
+    secp256k1::Scalar::from_byte_seq_be(x.to_byte_seq_be())
+}
+
+fn has_even_y(p: Affine) -> bool {
+    !p.1.bit(0)
+}
+
+/// lift_x: the point with x-coordinate `x` and even y.
+/// Fails if x >= p or there is no such point.
+pub fn lift_x(x: PublicKey) -> Result<Affine, String> {
+    let mut compressed = secp256k1::CompressedPoint::new();
+    compressed[0] = U8(0x02);
+    compressed = compressed.update(1, x);
+    secp256k1::deserialize_compressed(compressed)
+}
+
+// int(hash_challenge(bytes(R) || bytes(P) || m)) mod n
+fn challenge(r: ByteSeq, p: PublicKey, msg: ByteSeq) -> Secp256k1Scalar {
+    let mut h_in = ByteSeq::new(64 + msg.len());
+    h_in = h_in.update(0, r);
+    h_in = h_in.update(32, p);
+    h_in = h_in.update(64, msg);
+    Secp256k1Scalar::from_byte_seq_be(tagged_hash(ByteSeq::from(TAG_CHALLENGE), h_in))
+}
+
+// int(sk) in [1, n - 1]
+fn secret_scalar(sk: SecretKey) -> Result<Secp256k1Scalar, String> {
+    let d = Secp256k1Scalar::from_byte_seq_be(sk);
+    if scalar_bytes(d) != ByteSeq::from(sk.raw()) {
+        return Err("Secret key is not reduced".to_string());
+    }
+    if d == Secp256k1Scalar::from_literal(0) {
+        return Err("Secret key is zero".to_string());
+    }
+    Ok(d)
+}
+
+/// The x-only public key bytes(d' * G).
+pub fn public_key(sk: SecretKey) -> Result<PublicKey, String> {
+    let d = secret_scalar(sk)?;
+    Ok(point_bytes(secp256k1::point_mul_base(to_canvas(d))?))
+}
+
+/// Sign the message `msg` of arbitrary length with auxiliary randomness
+/// `aux_rand` (BIP-340 "Default Signing").
+/// The signature is verified before it is returned.
+pub fn sign(sk: SecretKey, msg: ByteSeq, aux_rand: AuxRand) -> Result<Signature, String> {
+    let d0 = secret_scalar(sk)?;
+    let p = secp256k1::point_mul_base(to_canvas(d0))?;
+    let d = if has_even_y(p) {
+        d0
+    } else {
+        Secp256k1Scalar::from_literal(0) - d0
+    };
+    let pk = point_bytes(p);
+
+    // t = bytes(d) xor hash_aux(a)
+    let aux_hash = tagged_hash(ByteSeq::from(TAG_AUX), ByteSeq::from(aux_rand.raw()));
+    let d_bytes = scalar_bytes(d);
+    let mut nonce_in = ByteSeq::new(64 + msg.len());
+    for i in 0..32 {
+        nonce_in[i] = d_bytes[i] ^ aux_hash[i];
+    }
+    // rand = hash_nonce(t || bytes(P) || m)
+    nonce_in = nonce_in.update(32, pk);
+    nonce_in = nonce_in.update(64, msg.clone());
+    let k0 = Secp256k1Scalar::from_byte_seq_be(tagged_hash(ByteSeq::from(TAG_NONCE), nonce_in));
+    if k0 == Secp256k1Scalar::from_literal(0) {
+        return Err("Invalid nonce".to_string());
+    }
+    let r = secp256k1::point_mul_base(to_canvas(k0))?;
+    let k = if has_even_y(r) {
+        k0
+    } else {
+        Secp256k1Scalar::from_literal(0) - k0
+    };
+    let r_bytes = ByteSeq::from(point_bytes(r).raw());
+    let e = challenge(r_bytes.clone(), pk, msg.clone());
+
+    let mut sig = Signature::new();
+    sig = sig.update(0, r_bytes);
+    sig = sig.update(32, scalar_bytes(k + e * d));
+    verify(pk, msg, sig)?;
+    Ok(sig)
+}
+
+/// Verify the signature `sig` of `msg` under the x-only public key `pk`.
+pub fn verify(pk: PublicKey, msg: ByteSeq, sig: Signature) -> Result<(), String> {
+    let p = lift_x(pk)?;
+    let sig = ByteSeq::from(sig.raw());
+    let r_bytes = sig.sub(0, 32);
+    let r = FieldElement::from_byte_seq_be(r_bytes.clone());
+    if field_element_bytes(r) != r_bytes {
+        return Err("r is not smaller than p".to_string());
+    }
+    let s = Secp256k1Scalar::from_byte_seq_be(sig.sub(32, 32));
+    if scalar_bytes(s) != sig.sub(32, 32) {
+        return Err("s is not smaller than n".to_string());
+    }
+    let e = challenge(r_bytes, pk, msg);
+
+    // R = s * G - e * P, which must not be the point at infinity.
+    let big_r = secp256k1::point_mul_add(
+        to_canvas(s),
+        to_canvas(Secp256k1Scalar::from_literal(0) - e),
+        p,
+    )?;
+    if !has_even_y(big_r) || big_r.0 != r {
+        return Err("Signature verification failed".to_string());
+    }
+    Ok(())
+}
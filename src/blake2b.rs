@@ -6,9 +6,15 @@ array!(DoubleState, 16, U64);
 array!(Counter, 2, u64);
 bytes!(Buffer, 128);
 bytes!(Digest, 64);
+bytes!(Salt, 16);
+bytes!(Personalization, 16);
 array!(Sigma, 16 * 12, usize);
 
-static SIGMA: Sigma = Sigma([
+const KEY_MAX_BYTES: usize = 64;
+const OUT_MAX_BYTES: usize = 64;
+
+// The message word permutations, shared with BLAKE2s which uses the first 10.
+pub(crate) static SIGMA: Sigma = Sigma([
     0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 14, 10, 4, 8, 9, 15, 13, 6, 1, 12, 0, 2,
     11, 7, 5, 3, 11, 8, 12, 0, 5, 2, 15, 13, 10, 14, 3, 6, 7, 1, 9, 4, 7, 9, 3, 1, 13, 12, 11, 14,
     2, 6, 5, 10, 4, 0, 15, 8, 9, 0, 5, 7, 2, 4, 10, 15, 14, 1, 11, 12, 6, 8, 3, 13, 2, 12, 6, 10,
@@ -134,23 +140,43 @@ fn get_byte(x: U64, i: usize) -> U8 {
     }
 }
 
-pub fn blake2b(data: ByteSeq) -> Digest {
+// The initial state: the IV xor the parameter block (RFC 7693 Section 2.5)
+// for sequential hashing, i.e. fanout and depth 1, with all tree
+// parameters 0.
+fn init(key_len: usize, out_len: usize, salt: Salt, personalization: Personalization) -> State {
+    let mut p = ByteSeq::new(64);
+    p[0] = U8(out_len as u8);
+    p[1] = U8(key_len as u8);
+    p[2] = U8(1u8);
+    p[3] = U8(1u8);
+    p = p.update(32, salt);
+    p = p.update(48, personalization);
     let mut h = IV;
-    // This only supports the 512 version without key.
-    h[0] = h[0] ^ U64(0x0101_0000) ^ U64(64);
+    for i in 0..8 {
+        h[i] = h[i] ^ u64_from_le_bytes(U64Word::from_sub(p.clone(), 8 * i..8 * i + 8));
+    }
+    h
+}
 
+// All blocks but the last are full. The last block is compressed with the
+// final flag even if it is empty (only for empty unkeyed input) or full.
+fn hash_blocks(h: State, data: ByteSeq) -> Digest {
+    let mut h = h;
     let mut t = Counter([0; 2]);
-    for (block_len, block) in data.chunks(128) {
-        if block_len == 128 {
-            t = inc_counter(t, 128);
-            h = compress(h, Buffer::from(block), t, false);
-        } else {
-            // Pad last bits of data to a full block.
-            t = inc_counter(t, block_len as u64);
-            h = compress(h, Buffer::from(block), t, true);
-        }
+    let blocks = if data.len() == 0 {
+        1
+    } else {
+        div_ceil(data.len(), 128)
+    };
+    for i in 0..blocks - 1 {
+        t = inc_counter(t, 128);
+        h = compress(h, Buffer::from(data.sub(128 * i, 128)), t, false);
     }
-
+    // Pad last bits of data to a full block.
+    let last_len = data.len() - 128 * (blocks - 1);
+    let last = Buffer::new().update_sub(0, data, 128 * (blocks - 1), last_len);
+    t = inc_counter(t, last_len as u64);
+    h = compress(h, last, t, true);
 
     // We transform 8*u64 into 64*u8
     let mut d = Digest::new();
@@ -161,3 +187,41 @@ pub fn blake2b(data: ByteSeq) -> Digest {
     }
     d
 }
+
+/// BLAKE2b-512 without a key.
+pub fn blake2b(data: ByteSeq) -> Digest {
+    hash_blocks(init(0, 64, Salt::new(), Personalization::new()), data)
+}
+
+/// BLAKE2b with a `key` of up to 64 bytes (empty for unkeyed hashing), an
+/// output of `out_len` bytes between 1 and 64, a salt and a
+/// personalization string.
+/// The key is padded to a full block and prepended to the data.
+pub fn blake2b_params(
+    data: ByteSeq,
+    key: ByteSeq,
+    out_len: usize,
+    salt: Salt,
+    personalization: Personalization,
+) -> Result<ByteSeq, String> {
+    if key.len() > KEY_MAX_BYTES {
+        return Err("Key is longer than 64 bytes".to_string());
+    }
+    if out_len < 1 || out_len > OUT_MAX_BYTES {
+        return Err("Output length must be between 1 and 64 bytes".to_string());
+    }
+    let h = init(key.len(), out_len, salt, personalization);
+    let data = if key.len() > 0 {
+        let mut keyed = ByteSeq::new(128 + data.len());
+        keyed = keyed.update(0, key);
+        keyed.update(128, data)
+    } else {
+        data
+    };
+    Ok(ByteSeq::from(hash_blocks(h, data).raw()).sub(0, out_len))
+}
+
+/// Keyed BLAKE2b (MAC mode) with an output of `out_len` bytes.
+pub fn blake2b_keyed(data: ByteSeq, key: ByteSeq, out_len: usize) -> Result<ByteSeq, String> {
+    blake2b_params(data, key, out_len, Salt::new(), Personalization::new())
+}
@@ -0,0 +1,169 @@
+// Import hacspec and all needed definitions.
+use hacspec::prelude::*;
+
+// BLAKE2s uses the same message word permutations as BLAKE2b.
+use crate::blake2b::SIGMA;
+
+array!(State, 8, U32);
+array!(DoubleState, 16, U32);
+bytes!(Buffer, 64);
+bytes!(Digest, 32);
+bytes!(Salt, 8);
+bytes!(Personalization, 8);
+
+const KEY_MAX_BYTES: usize = 32;
+const OUT_MAX_BYTES: usize = 32;
+
+// The SHA-256 IV
+const IV: State = State(secret_array!(
+    U32,
+    [
+        0x6a09_e667u32,
+        0xbb67_ae85u32,
+        0x3c6e_f372u32,
+        0xa54f_f53au32,
+        0x510e_527fu32,
+        0x9b05_688cu32,
+        0x1f83_d9abu32,
+        0x5be0_cd19u32
+    ]
+));
+
+fn mix(v: DoubleState, a: usize, b: usize, c: usize, d: usize, x: U32, y: U32) -> DoubleState {
+    let mut result = v;
+    result[a] = result[a] + result[b] + x;
+    result[d] = (result[d] ^ result[a]).rotate_right(16);
+
+    result[c] = result[c] + result[d];
+    result[b] = (result[b] ^ result[c]).rotate_right(12);
+
+    result[a] = result[a] + result[b] + y;
+    result[d] = (result[d] ^ result[a]).rotate_right(8);
+
+    result[c] = result[c] + result[d];
+    result[b] = (result[b] ^ result[c]).rotate_right(7);
+
+    result
+}
+
+// The byte counter t is at most 2^64 - 1 and is split into two words.
+fn compress(h: State, m: Buffer, t: u64, last_block: bool) -> State {
+    let mut v = DoubleState::new();
+
+    // Read u8 data to u32.
+    let mut w = DoubleState::new();
+    for i in 0..16 {
+        w[i] = u32_from_le_bytes(U32Word::from_sub(m, 4 * i..4 * i + 4));
+    }
+
+    // Prepare.
+    v = v.update_sub(0, h, 0, 8);
+    v = v.update_sub(8, IV, 0, 8);
+    v[12] ^= U32(t as u32);
+    v[13] ^= U32((t >> 32) as u32);
+    if last_block {
+        let old_v: U32 = v[14];
+        v[14] = !old_v;
+    }
+
+    // Mixing.
+    for i in 0..10 {
+        let s = i * 16;
+        v = mix(v, 0, 4, 8, 12, w[SIGMA[s + 0]], w[SIGMA[s + 1]]);
+        v = mix(v, 1, 5, 9, 13, w[SIGMA[s + 2]], w[SIGMA[s + 3]]);
+        v = mix(v, 2, 6, 10, 14, w[SIGMA[s + 4]], w[SIGMA[s + 5]]);
+        v = mix(v, 3, 7, 11, 15, w[SIGMA[s + 6]], w[SIGMA[s + 7]]);
+        v = mix(v, 0, 5, 10, 15, w[SIGMA[s + 8]], w[SIGMA[s + 9]]);
+        v = mix(v, 1, 6, 11, 12, w[SIGMA[s + 10]], w[SIGMA[s + 11]]);
+        v = mix(v, 2, 7, 8, 13, w[SIGMA[s + 12]], w[SIGMA[s + 13]]);
+        v = mix(v, 3, 4, 9, 14, w[SIGMA[s + 14]], w[SIGMA[s + 15]]);
+    }
+
+    let mut compressed = State::new();
+    for i in 0..8 {
+        compressed[i] = h[i] ^ v[i] ^ v[i + 8];
+    }
+    compressed
+}
+
+// The initial state: the IV xor the 32 byte parameter block for sequential
+// hashing, i.e. fanout and depth 1, with all tree parameters 0.
+fn init(key_len: usize, out_len: usize, salt: Salt, personalization: Personalization) -> State {
+    let mut p = ByteSeq::new(32);
+    p[0] = U8(out_len as u8);
+    p[1] = U8(key_len as u8);
+    p[2] = U8(1u8);
+    p[3] = U8(1u8);
+    p = p.update(16, salt);
+    p = p.update(24, personalization);
+    let mut h = IV;
+    for i in 0..8 {
+        h[i] = h[i] ^ u32_from_le_bytes(U32Word::from_sub(p.clone(), 4 * i..4 * i + 4));
+    }
+    h
+}
+
+// All blocks but the last are full. The last block is compressed with the
+// final flag even if it is empty (only for empty unkeyed input) or full.
+fn hash_blocks(h: State, data: ByteSeq) -> Digest {
+    let mut h = h;
+    let mut t = 0u64;
+    let blocks = if data.len() == 0 {
+        1
+    } else {
+        div_ceil(data.len(), 64)
+    };
+    for i in 0..blocks - 1 {
+        t = t + 64;
+        h = compress(h, Buffer::from(data.sub(64 * i, 64)), t, false);
+    }
+    // Pad last bits of data to a full block.
+    let last_len = data.len() - 64 * (blocks - 1);
+    let last = Buffer::new().update_sub(0, data, 64 * (blocks - 1), last_len);
+    t = t + last_len as u64;
+    h = compress(h, last, t, true);
+
+    let mut d = Digest::new();
+    for i in 0..8 {
+        d = d.update(4 * i, u32_to_le_bytes(h[i]));
+    }
+    d
+}
+
+/// BLAKE2s-256 without a key.
+pub fn blake2s(data: ByteSeq) -> Digest {
+    hash_blocks(init(0, 32, Salt::new(), Personalization::new()), data)
+}
+
+/// BLAKE2s with a `key` of up to 32 bytes (empty for unkeyed hashing), an
+/// output of `out_len` bytes between 1 and 32, a salt and a
+/// personalization string.
+/// The key is padded to a full block and prepended to the data.
+pub fn blake2s_params(
+    data: ByteSeq,
+    key: ByteSeq,
+    out_len: usize,
+    salt: Salt,
+    personalization: Personalization,
+) -> Result<ByteSeq, String> {
+    if key.len() > KEY_MAX_BYTES {
+        return Err("Key is longer than 32 bytes".to_string());
+    }
+    if out_len < 1 || out_len > OUT_MAX_BYTES {
+        return Err("Output length must be between 1 and 32 bytes".to_string());
+    }
+    let h = init(key.len(), out_len, salt, personalization);
+    let data = if key.len() > 0 {
+        let mut keyed = ByteSeq::new(64 + data.len());
+        keyed = keyed.update(0, key);
+        keyed.update(64, data)
+    } else {
+        data
+    };
+    Ok(ByteSeq::from(hash_blocks(h, data).raw()).sub(0, out_len))
+}
+
+/// Keyed BLAKE2s (MAC mode) with an output of `out_len` bytes.
+pub fn blake2s_keyed(data: ByteSeq, key: ByteSeq, out_len: usize) -> Result<ByteSeq, String> {
+    blake2s_params(data, key, out_len, Salt::new(), Personalization::new())
+}
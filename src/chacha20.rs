@@ -5,6 +5,8 @@ array!(State, 16, U32);
 bytes!(StateBytes, 64);
 bytes!(IV, 12);
 bytes!(Key, 32);
+bytes!(HChaChaIV, 16);
+bytes!(DJBIV, 8);
 
 pub fn state_to_bytes(x: State) -> StateBytes {
     let mut r = StateBytes::new();
@@ -67,10 +69,22 @@ pub fn block_init(key: Key, ctr: U32, iv: IV) -> State {
     ])
 }
 
-pub fn block_inner(key: Key, ctr: U32, iv: IV) -> State {
-    let st = block_init(key, ctr, iv);
+/// The original ChaCha layout by Bernstein with a 64-bit block counter
+/// in words 12 and 13, and a 64-bit nonce in words 14 and 15.
+pub fn block_init_djb(key: Key, ctr: U64, iv: DJBIV) -> State {
+    let mut state = block_init(key, U32(0), IV::new());
+    state[12] = U32::from(ctr & U64(0xffff_ffff));
+    state[13] = U32::from(ctr >> 32);
+    state[14] = u32_from_le_bytes(U32Word::from_sub(iv, 0..4));
+    state[15] = u32_from_le_bytes(U32Word::from_sub(iv, 4..8));
+    state
+}
+
+// Run `rounds` ChaCha rounds on the initial state and add it back in.
+// `rounds` is 20 for ChaCha20, and 12 or 8 for the reduced-round variants.
+fn chacha_core(st: State, rounds: usize) -> State {
     let mut state = st;
-    for _ in 0..10 {
+    for _ in 0..rounds / 2 {
         state = double_round(state);
     }
     for i in 0..16 {
@@ -79,13 +93,40 @@ pub fn block_inner(key: Key, ctr: U32, iv: IV) -> State {
     state
 }
 
+pub fn block_inner(key: Key, ctr: U32, iv: IV) -> State {
+    chacha_core(block_init(key, ctr, iv), 20)
+}
+
 pub fn block(key: Key, ctr: U32, iv: IV) -> StateBytes {
     let state = block_inner(key, ctr, iv);
     state_to_bytes(state)
 }
 
-pub fn chacha(key: Key, iv: IV, m: ByteSeq) -> Result<ByteSeq, String> {
-    let mut ctr = U32(1);
+/// HChaCha20 derives a 256-bit subkey from a key and a 128-bit nonce.
+/// It runs the ChaCha20 rounds on the state without the final addition and
+/// outputs the first and last row of the state.
+pub fn hchacha(key: Key, iv: HChaChaIV) -> Key {
+    let ctr = u32_from_le_bytes(U32Word::from_sub(iv, 0..4));
+    let mut state = block_init(key, ctr, IV::from_sub(iv, 4..16));
+    for _ in 0..10 {
+        state = double_round(state);
+    }
+    let state_bytes = state_to_bytes(state);
+    let mut subkey = Key::new();
+    subkey = subkey.update_sub(0, state_bytes, 0, 16);
+    subkey = subkey.update_sub(16, state_bytes, 48, 16);
+    subkey
+}
+
+/// Encrypt `m` with the keystream starting at block `ctr`.
+/// The 32-bit block counter must not wrap, i.e. `m` can be at most
+/// `(2^32 - ctr) * 64` bytes long.
+pub fn chacha_at(key: Key, iv: IV, ctr: u32, m: ByteSeq) -> Result<ByteSeq, String> {
+    let n_blocks = div_ceil(m.len(), 64) as u64;
+    if ctr as u64 + n_blocks > 1u64 << 32 {
+        return Err("ChaCha20 block counter overflow".to_string());
+    }
+    let mut ctr = U32(ctr);
     let mut blocks_out = ByteSeq::new(m.len());
     for (block_len, msg_block) in m.chunks(64) {
         let key_block = block(key, ctr, iv);
@@ -94,3 +135,63 @@ pub fn chacha(key: Key, iv: IV, m: ByteSeq) -> Result<ByteSeq, String> {
     }
     Ok(blocks_out)
 }
+
+/// Encrypt `m` with the keystream of `chacha` starting at byte `offset`.
+/// This allows decrypting parts of a long stream without starting at 0.
+pub fn chacha_seek(key: Key, iv: IV, offset: u64, m: ByteSeq) -> Result<ByteSeq, String> {
+    let ctr = 1 + offset / 64;
+    if ctr > u32::max_value() as u64 {
+        return Err("ChaCha20 block counter overflow".to_string());
+    }
+    // Skip the first bytes of the keystream block the offset points into.
+    let skip = (offset % 64) as usize;
+    let mut padded_msg = ByteSeq::new(skip + m.len());
+    padded_msg = padded_msg.update(skip, m.clone());
+    match chacha_at(key, iv, ctr as u32, padded_msg) {
+        Ok(c) => Ok(c.sub(skip, m.len())),
+        Err(r) => Err(r),
+    }
+}
+
+pub fn chacha(key: Key, iv: IV, m: ByteSeq) -> Result<ByteSeq, String> {
+    chacha_at(key, iv, 1, m)
+}
+
+// Only ChaCha8, ChaCha12 and ChaCha20 are defined.
+fn check_rounds(rounds: usize) -> Result<(), String> {
+    match rounds {
+        8 | 12 | 20 => Ok(()),
+        _ => Err("ChaCha rounds must be 8, 12 or 20".to_string()),
+    }
+}
+
+pub fn block_djb(key: Key, ctr: U64, iv: DJBIV, rounds: usize) -> Result<StateBytes, String> {
+    check_rounds(rounds)?;
+    let state = chacha_core(block_init_djb(key, ctr, iv), rounds);
+    Ok(state_to_bytes(state))
+}
+
+/// ChaCha with `rounds` rounds in the original layout, starting at block 0.
+pub fn chacha_djb(key: Key, iv: DJBIV, rounds: usize, m: ByteSeq) -> Result<ByteSeq, String> {
+    check_rounds(rounds)?;
+    let mut ctr = U64(0);
+    let mut blocks_out = ByteSeq::new(m.len());
+    for (block_len, msg_block) in m.chunks(64) {
+        let key_block = state_to_bytes(chacha_core(block_init_djb(key, ctr, iv), rounds));
+        blocks_out = blocks_out.push_sub(StateBytes::from(msg_block) ^ key_block, 0, block_len);
+        ctr += U64(1);
+    }
+    Ok(blocks_out)
+}
+
+pub fn chacha8_djb(key: Key, iv: DJBIV, m: ByteSeq) -> Result<ByteSeq, String> {
+    chacha_djb(key, iv, 8, m)
+}
+
+pub fn chacha12_djb(key: Key, iv: DJBIV, m: ByteSeq) -> Result<ByteSeq, String> {
+    chacha_djb(key, iv, 12, m)
+}
+
+pub fn chacha20_djb(key: Key, iv: DJBIV, m: ByteSeq) -> Result<ByteSeq, String> {
+    chacha_djb(key, iv, 20, m)
+}
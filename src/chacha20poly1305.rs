@@ -51,8 +51,7 @@ pub fn decrypt(
     let key_block = block(key, U32(0), iv);
     let mac_key = Key::from_sub(key_block, 0..32);
     let padded_msg = pad_aad_msg(aad, cipher_text.clone());
-    let my_tag = poly(padded_msg, mac_key);
-    if my_tag == tag {
+    if Poly1305::new(mac_key).update(padded_msg).verify(tag) {
         match chacha(key, iv, cipher_text) {
             Ok(c) => Ok(c),
             Err(r) => {
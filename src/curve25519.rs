@@ -28,6 +28,7 @@ fn mask_scalar(s: SerializedScalar) -> SerializedScalar {
 }
 
 // TODO: drop raw where possible
+#[cfg(test)]
 fn decode_scalar(s: SerializedScalar) -> Scalar {
     let k = mask_scalar(s);
     Scalar::from_byte_seq_le(k)
@@ -70,11 +71,52 @@ fn point_add_and_double(q: Point, (nq, nqp1): (Point, Point)) -> (Point, Point)
     ((x_2, z_2), (x_3, z_3))
 }
 
+// Get bit `i` of the little-endian scalar `k` as 0 or 1.
+fn scalar_bit(k: SerializedScalar, i: usize) -> U8 {
+    (k[i / 8] >> (i % 8) as u32) & U8(1)
+}
+
+// Swap the two points if `swap` is 1 and leave them if it is 0.
+// The selection is done with field arithmetic such that `swap` doesn't
+// determine the control flow.
+fn cswap(swap: U8, (p, q): (Point, Point)) -> (Point, Point) {
+    let swap = FieldElement::from_secret_literal(U128::from(swap));
+    let (x_2, z_2) = p;
+    let (x_3, z_3) = q;
+    let dummy_x = swap * (x_2 - x_3);
+    let dummy_z = swap * (z_2 - z_3);
+    (
+        (x_2 - dummy_x, z_2 - dummy_z),
+        (x_3 + dummy_x, z_3 + dummy_z),
+    )
+}
+
+// The ladder from RFC 7748 Section 5.
+// The scalar is a secret byte array and can't be branched on.
+fn montgomery_ladder(k: SerializedScalar, init: Point) -> Point {
+    let inf = (FieldElement::from_literal(1), FieldElement::from_literal(0));
+    let mut acc: (Point, Point) = (inf, init);
+    let mut swap = U8(0);
+    for i in 0..256 {
+        let k_t = scalar_bit(k, 255 - i);
+        swap = swap ^ k_t;
+        acc = cswap(swap, acc);
+        swap = k_t;
+        acc = point_add_and_double(init, acc);
+    }
+    acc = cswap(swap, acc);
+    acc.0
+}
+
+// The branching ladder.
+// This is only used as test oracle for the constant-time ladder above.
+#[cfg(test)]
 fn swap(x: (Point, Point)) -> (Point, Point) {
     (x.1, x.0)
 }
 
-fn montgomery_ladder(k: Scalar, init: Point) -> Point {
+#[cfg(test)]
+fn montgomery_ladder_branching(k: Scalar, init: Point) -> Point {
     // TODO: let inf = (FieldElement::one(), FieldElement::zero());
     let inf = (FieldElement::from_literal(1), FieldElement::from_literal(0));
     let mut acc: (Point, Point) = (inf, init);
@@ -91,12 +133,28 @@ fn montgomery_ladder(k: Scalar, init: Point) -> Point {
 }
 
 pub fn scalarmult(s: SerializedScalar, p: SerializedPoint) -> SerializedPoint {
-    let s_ = decode_scalar(s);
+    let s_ = mask_scalar(s);
     let p_ = decode_point(p);
     let r = montgomery_ladder(s_, p_);
     encode_point(r)
 }
 
+/// X25519 as in RFC 7748 Section 6.1.
+/// Fails if the shared secret is all zero, i.e. when `u` is a point of low
+/// order. The check is done without branching on the individual bytes.
+pub fn x25519(s: SerializedScalar, u: SerializedPoint) -> Result<SerializedPoint, String> {
+    let r = scalarmult(s, u);
+    let mut acc = U8(0);
+    for i in 0..r.len() {
+        acc |= r[i];
+    }
+    if acc.declassify() == 0 {
+        Err("X25519 shared secret is all zero".to_string())
+    } else {
+        Ok(r)
+    }
+}
+
 pub fn secret_to_public(s: SerializedScalar) -> SerializedPoint {
     let base = SerializedPoint::from("09");
     scalarmult(s, base)
@@ -131,3 +189,16 @@ fn test_encode_decode_scalar() {
     let u_encoded = encode_point(u_);
     assert_eq!(u, u_encoded);
 }
+
+#[test]
+fn test_ladder_oracle() {
+    let u = decode_point(SerializedPoint::from(
+        "e6db6867583030db3594c1a424b15f7c726624ec26b3353b10a903a6d0ab1c4c",
+    ));
+    for _ in 0..10 {
+        let s = SerializedScalar::random();
+        let r = montgomery_ladder(mask_scalar(s), u);
+        let r_expected = montgomery_ladder_branching(decode_scalar(s), u);
+        assert_eq!(encode_point(r_expected), encode_point(r));
+    }
+}
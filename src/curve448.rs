@@ -0,0 +1,118 @@
+// Import hacspec and all needed definitions.
+use hacspec::prelude::*;
+
+unsigned_integer!(FieldCanvas, 448);
+
+// Define field mod 2^448 - 2^224 - 1
+field_integer!(
+    FieldElement,
+    FieldCanvas,
+    FieldCanvas::from_hex("fffffffffffffffffffffffffffffffffffffffffffffffffffffffeffffffffffffffffffffffffffffffffffffffffffffffffffffffff")
+);
+
+type Point = (FieldElement, FieldElement);
+bytes!(SerializedPoint, 56);
+bytes!(SerializedScalar, 56);
+
+fn mask_scalar(s: SerializedScalar) -> SerializedScalar {
+    let mut k = s;
+    k[0] &= U8(252);
+    k[55] |= U8(128);
+    k
+}
+
+// Unlike X25519 all bits of u are used.
+fn decode_point(u: SerializedPoint) -> Point {
+    (
+        FieldElement::from_byte_seq_le(u),
+        FieldElement::from_literal(1),
+    )
+}
+
+fn encode_point(p: Point) -> SerializedPoint {
+    let (x, y) = p;
+    let b = x * y.inv();
+    SerializedPoint::copy_pad(b.to_byte_seq_le())
+}
+
+fn point_add_and_double(q: Point, (nq, nqp1): (Point, Point)) -> (Point, Point) {
+    let (x_1, _z_1) = q;
+    let (x_2, z_2) = nq;
+    let (x_3, z_3) = nqp1;
+    let a = x_2 + z_2;
+    let aa = a.pow(2);
+    let b = x_2 - z_2;
+    let bb = b * b;
+    let e = aa - bb;
+    let c = x_3 + z_3;
+    let d = x_3 - z_3;
+    let da = d * a;
+    let cb = c * b;
+
+    let x_3 = (da + cb).pow(2);
+    let z_3 = x_1 * ((da - cb).pow(2));
+    let x_2 = aa * bb;
+    let e39081 = FieldElement::from_literal(39_081);
+    let z_2 = e * (aa + (e39081 * e));
+    ((x_2, z_2), (x_3, z_3))
+}
+
+// Get bit `i` of the little-endian scalar `k` as 0 or 1.
+fn scalar_bit(k: SerializedScalar, i: usize) -> U8 {
+    (k[i / 8] >> (i % 8) as u32) & U8(1)
+}
+
+// Swap the two points if `swap` is 1 and leave them if it is 0.
+fn cswap(swap: U8, (p, q): (Point, Point)) -> (Point, Point) {
+    let swap = FieldElement::from_secret_literal(U128::from(swap));
+    let (x_2, z_2) = p;
+    let (x_3, z_3) = q;
+    let dummy_x = swap * (x_2 - x_3);
+    let dummy_z = swap * (z_2 - z_3);
+    (
+        (x_2 - dummy_x, z_2 - dummy_z),
+        (x_3 + dummy_x, z_3 + dummy_z),
+    )
+}
+
+fn montgomery_ladder(k: SerializedScalar, init: Point) -> Point {
+    let inf = (FieldElement::from_literal(1), FieldElement::from_literal(0));
+    let mut acc: (Point, Point) = (inf, init);
+    let mut swap = U8(0);
+    for i in 0..448 {
+        let k_t = scalar_bit(k, 447 - i);
+        swap = swap ^ k_t;
+        acc = cswap(swap, acc);
+        swap = k_t;
+        acc = point_add_and_double(init, acc);
+    }
+    acc = cswap(swap, acc);
+    acc.0
+}
+
+pub fn scalarmult(s: SerializedScalar, p: SerializedPoint) -> SerializedPoint {
+    let s_ = mask_scalar(s);
+    let p_ = decode_point(p);
+    let r = montgomery_ladder(s_, p_);
+    encode_point(r)
+}
+
+/// X448 as in RFC 7748 Section 6.2.
+/// Fails if the shared secret is all zero.
+pub fn x448(s: SerializedScalar, u: SerializedPoint) -> Result<SerializedPoint, String> {
+    let r = scalarmult(s, u);
+    let mut acc = U8(0);
+    for i in 0..r.len() {
+        acc |= r[i];
+    }
+    if acc.declassify() == 0 {
+        Err("X448 shared secret is all zero".to_string())
+    } else {
+        Ok(r)
+    }
+}
+
+pub fn secret_to_public(s: SerializedScalar) -> SerializedPoint {
+    let base = SerializedPoint::from("05");
+    scalarmult(s, base)
+}
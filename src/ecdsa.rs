@@ -0,0 +1,473 @@
+// Import hacspec and all needed definitions.
+use hacspec::prelude::*;
+
+// Import primitives
+use crate::hmac::hmac;
+use crate::p256::{self, P256Scalar};
+use crate::sha2;
+
+// ECDSA (FIPS 186-4) with deterministic nonces from RFC 6979.
+// The top-level functions use P-256 with SHA-256. The modules `p384` and
+// `p521` use P-384 with SHA-384 and P-521 with SHA-512. The module
+// `secp256k1` uses secp256k1 with SHA-256 and adds public key recovery.
+//
+// `ecdsa_scheme!` defines the scheme for a curve module created with
+// `weierstrass_curve!`. In all instances the hash is at most as long as the
+// group order n, so bits2int(H(m)) never has to drop bits of the hash.
+macro_rules! ecdsa_scheme {
+    (
+        curve: $curve:ident,
+        scalar_field: $sf:ident,
+        digest: $digest:ty,
+        hash: $hash:path,
+        hmac: $hmac:path,
+        scalar_bits: $sbits:expr,
+        scalar_bytes: $slen:expr,
+        hash_bytes: $hlen:expr $(,)?
+    ) => {
+        const SCALAR_BITS: usize = $sbits;
+        const SCALAR_BYTES: usize = $slen;
+        const HASH_BYTES: usize = $hlen;
+
+        pub type Signature = ($sf, $sf);
+
+        bytes!(SerializedScalar, SCALAR_BYTES);
+        // Fixed-width encoding r || s
+        bytes!(SignatureBytes, 2 * SCALAR_BYTES);
+
+        // Fixed-length big-endian encoding, including leading zeros
+        fn scalar_to_be_bytes(x: $sf) -> SerializedScalar {
+            let b = x.to_byte_seq_be();
+            SerializedScalar::new().update(SCALAR_BYTES - b.len(), b)
+        }
+
+        // Decode a scalar and fail if it is zero or not reduced mod n.
+        fn scalar_from_be_bytes(b: SerializedScalar) -> Result<$sf, String> {
+            let x = $sf::from_byte_seq_be(b);
+            if scalar_to_be_bytes(x) != b {
+                return Err("Scalar is not reduced".to_string());
+            }
+            if x == $sf::from_literal(0) {
+                return Err("Scalar is zero".to_string());
+            }
+            Ok(x)
+        }
+
+        fn to_canvas(x: $sf) -> $curve::Scalar {
+            $curve::Scalar::from_byte_seq_be(x.to_byte_seq_be())
+        }
+
+        // bits2int(H(m)) mod n
+        fn hash_to_scalar(h: $digest) -> $sf {
+            $sf::from_byte_seq_be(h)
+        }
+
+        // bits2int from RFC 6979 Section 2.3.2 for the nonce candidates: the
+        // leftmost qlen bits of `b`, which has at least qlen bits.
+        fn bits2int(b: ByteSeq) -> SerializedScalar {
+            let shift = 8 * SCALAR_BYTES - SCALAR_BITS;
+            let mut out = SerializedScalar::from(b.sub(0, SCALAR_BYTES));
+            if shift > 0 {
+                for i in 0..SCALAR_BYTES {
+                    let high = if i > 0 {
+                        b[i - 1] << (8 - shift) as u32
+                    } else {
+                        U8(0)
+                    };
+                    out[i] = (b[i] >> shift as u32) | high;
+                }
+            }
+            out
+        }
+
+        /// The deterministic nonce from RFC 6979 Section 3.2.
+        pub fn rfc6979_nonce(sk: $sf, h: $digest) -> $sf {
+            let x = scalar_to_be_bytes(sk);
+            let h1 = scalar_to_be_bytes(hash_to_scalar(h));
+            let mut v = ByteSeq::new(HASH_BYTES);
+            for i in 0..HASH_BYTES {
+                v[i] = U8(0x01);
+            }
+            let mut k = ByteSeq::new(HASH_BYTES);
+            for i in 0..2 {
+                // K = HMAC_K(V || i || int2octets(x) || bits2octets(h1))
+                let mut k_in = ByteSeq::new(HASH_BYTES + 1 + 2 * SCALAR_BYTES);
+                k_in = k_in.update(0, v.clone());
+                k_in[HASH_BYTES] = U8(i as u8);
+                k_in = k_in.update(HASH_BYTES + 1, x);
+                k_in = k_in.update(HASH_BYTES + 1 + SCALAR_BYTES, h1);
+                k = $hmac(k, k_in);
+                v = $hmac(k.clone(), v);
+            }
+            // Number of HMAC outputs needed for qlen bits
+            let n_blocks = div_ceil(SCALAR_BYTES, HASH_BYTES);
+            loop {
+                // T = V_1 || ... || V_n_blocks
+                let mut t = ByteSeq::new(n_blocks * HASH_BYTES);
+                for j in 0..n_blocks {
+                    v = $hmac(k.clone(), v);
+                    t = t.update(j * HASH_BYTES, v.clone());
+                }
+                match scalar_from_be_bytes(bits2int(t)) {
+                    Ok(nonce) => return nonce,
+                    Err(_) => {
+                        // K = HMAC_K(V || 0x00)
+                        let mut k_in = ByteSeq::new(HASH_BYTES + 1);
+                        k_in = k_in.update(0, v.clone());
+                        k = $hmac(k, k_in);
+                        v = $hmac(k.clone(), v);
+                    }
+                }
+            }
+        }
+
+        /// Normalize s to the lower half of [1, n - 1].
+        /// Both (r, s) and (r, n - s) are valid signatures. Some protocols
+        /// only accept the low-S form to rule out this malleability.
+        pub fn normalize_s(sig: Signature) -> Signature {
+            let (r, s) = sig;
+            // s > (n - 1) / 2 if and only if 2s >= n, i.e. if 2s mod n = 2s - n
+            // is odd.
+            if ($sf::from_literal(2) * s).bit(0) {
+                (r, $sf::from_literal(0) - s)
+            } else {
+                (r, s)
+            }
+        }
+
+        // The signature (r, s) together with the point R = k * G whose
+        // x-coordinate gives r.
+        fn sign_with_nonce_point(
+            sk: $sf,
+            msg: ByteSeq,
+        ) -> Result<(Signature, $curve::Affine), String> {
+            if sk == $sf::from_literal(0) {
+                return Err("Secret key is zero".to_string());
+            }
+            let h = $hash(msg);
+            let e = hash_to_scalar(h);
+            let k = rfc6979_nonce(sk, h);
+            let (x, y) = $curve::point_mul_base(to_canvas(k))?;
+            let r = $sf::from_byte_seq_be(x.to_byte_seq_be());
+            let s = k.inv() * (e + r * sk);
+            // This happens with negligible probability.
+            if r == $sf::from_literal(0) || s == $sf::from_literal(0) {
+                return Err("Invalid nonce".to_string());
+            }
+            Ok(((r, s), (x, y)))
+        }
+
+        /// Sign `msg` with the secret key `sk`.
+        /// With `low_s` the signature is normalized with `normalize_s`.
+        pub fn sign(sk: $sf, msg: ByteSeq, low_s: bool) -> Result<Signature, String> {
+            let (sig, _) = sign_with_nonce_point(sk, msg)?;
+            if low_s {
+                Ok(normalize_s(sig))
+            } else {
+                Ok(sig)
+            }
+        }
+
+        pub fn public_key(sk: $sf) -> Result<$curve::Affine, String> {
+            $curve::point_mul_base(to_canvas(sk))
+        }
+
+        pub fn verify(pk: $curve::Affine, msg: ByteSeq, sig: Signature) -> Result<(), String> {
+            let (r, s) = sig;
+            if r == $sf::from_literal(0) || s == $sf::from_literal(0) {
+                return Err("Invalid signature".to_string());
+            }
+            if !$curve::is_point_on_curve(pk) {
+                return Err("Public key is not on the curve".to_string());
+            }
+            let e = hash_to_scalar($hash(msg));
+            let w = s.inv();
+            let u1 = e * w;
+            let u2 = r * w;
+            let (x, _) = $curve::point_mul_add(to_canvas(u1), to_canvas(u2), pk)?;
+            if $sf::from_byte_seq_be(x.to_byte_seq_be()) == r {
+                Ok(())
+            } else {
+                Err("Signature verification failed".to_string())
+            }
+        }
+
+        pub fn signature_to_bytes(sig: Signature) -> SignatureBytes {
+            let (r, s) = sig;
+            let mut out = SignatureBytes::new();
+            out = out.update(0, scalar_to_be_bytes(r));
+            out = out.update(SCALAR_BYTES, scalar_to_be_bytes(s));
+            out
+        }
+
+        pub fn signature_from_bytes(b: SignatureBytes) -> Result<Signature, String> {
+            let r = scalar_from_be_bytes(SerializedScalar::from_sub(b, 0..SCALAR_BYTES))?;
+            let s = scalar_from_be_bytes(SerializedScalar::from_sub(
+                b,
+                SCALAR_BYTES..2 * SCALAR_BYTES,
+            ))?;
+            Ok((r, s))
+        }
+
+        // A positive DER INTEGER with the minimal number of bytes.
+        fn der_integer(x: $sf) -> ByteSeq {
+            let b = scalar_to_be_bytes(x);
+            let mut start = 0;
+            while start < SCALAR_BYTES - 1 && b[start].declassify() == 0 {
+                start += 1;
+            }
+            let pad = if b[start].declassify() >= 0x80 { 1 } else { 0 };
+            let len = SCALAR_BYTES - start + pad;
+            let mut out = ByteSeq::new(2 + len);
+            out[0] = U8(0x02);
+            out[1] = U8(len as u8);
+            out = out.update_sub(2 + pad, b, start, SCALAR_BYTES - start);
+            out
+        }
+
+        /// DER encoding of the ASN.1 structure SEQUENCE { r INTEGER, s INTEGER }.
+        /// Sequences of 128 bytes or more (only with P-521) use the long
+        /// length form 0x81 || length.
+        pub fn signature_to_der(sig: Signature) -> ByteSeq {
+            let (r, s) = sig;
+            let r_der = der_integer(r);
+            let s_der = der_integer(s);
+            let len = r_der.len() + s_der.len();
+            let header = if len < 0x80 { 2 } else { 3 };
+            let mut out = ByteSeq::new(header + len);
+            out[0] = U8(0x30);
+            if len < 0x80 {
+                out[1] = U8(len as u8);
+            } else {
+                out[1] = U8(0x81);
+                out[2] = U8(len as u8);
+            }
+            out = out.update(header, r_der.clone());
+            out = out.update(header + r_der.len(), s_der);
+            out
+        }
+
+        // Parse a DER INTEGER at `offset` and return it with the offset after
+        // it.
+        fn parse_der_integer(der: &ByteSeq, offset: usize) -> Result<($sf, usize), String> {
+            if offset + 2 > der.len() || der[offset].declassify() != 0x02 {
+                return Err("Invalid DER integer".to_string());
+            }
+            let len = der[offset + 1].declassify() as usize;
+            if len == 0 || len > SCALAR_BYTES + 1 || offset + 2 + len > der.len() {
+                return Err("Invalid DER integer length".to_string());
+            }
+            let first = der[offset + 2].declassify();
+            if first >= 0x80 {
+                return Err("Negative DER integer".to_string());
+            }
+            if len > 1 && first == 0 && der[offset + 3].declassify() < 0x80 {
+                return Err("DER integer is not minimally encoded".to_string());
+            }
+            if len == SCALAR_BYTES + 1 && first != 0 {
+                return Err("DER integer is too large".to_string());
+            }
+            // Drop the sign byte and left-pad to SCALAR_BYTES bytes.
+            let (start, n_bytes) = if len == SCALAR_BYTES + 1 {
+                (offset + 3, SCALAR_BYTES)
+            } else {
+                (offset + 2, len)
+            };
+            let b = SerializedScalar::new().update_sub(
+                SCALAR_BYTES - n_bytes,
+                der.clone(),
+                start,
+                n_bytes,
+            );
+            Ok((scalar_from_be_bytes(b)?, offset + 2 + len))
+        }
+
+        /// Strictly parse a DER encoded signature.
+        pub fn signature_from_der(der: ByteSeq) -> Result<Signature, String> {
+            if der.len() < 8 || der[0].declassify() != 0x30 {
+                return Err("Invalid DER signature".to_string());
+            }
+            let (len, offset) = match der[1].declassify() {
+                0x81 => (der[2].declassify() as usize, 3),
+                l if l < 0x80 => (l as usize, 2),
+                _ => return Err("Invalid DER signature length".to_string()),
+            };
+            if offset == 3 && len < 0x80 {
+                return Err("DER length is not minimally encoded".to_string());
+            }
+            if len != der.len() - offset {
+                return Err("Invalid DER signature length".to_string());
+            }
+            let (r, offset) = parse_der_integer(&der, offset)?;
+            let (s, offset) = parse_der_integer(&der, offset)?;
+            if offset != der.len() {
+                return Err("Trailing data in DER signature".to_string());
+            }
+            Ok((r, s))
+        }
+    };
+}
+
+fn hmac_sha256(k: ByteSeq, txt: ByteSeq) -> ByteSeq {
+    ByteSeq::from(hmac(k, txt).raw())
+}
+
+ecdsa_scheme!(
+    curve: p256,
+    scalar_field: P256Scalar,
+    digest: sha2::Digest,
+    hash: sha2::hash,
+    hmac: hmac_sha256,
+    scalar_bits: 256,
+    scalar_bytes: 32,
+    hash_bytes: 32,
+);
+
+/// ECDSA with P-384 and SHA-384.
+pub mod p384 {
+    use hacspec::prelude::*;
+
+    use crate::hmac::hmac_sha384;
+    use crate::p384::{self, P384Scalar};
+    use crate::sha2;
+
+    ecdsa_scheme!(
+        curve: p384,
+        scalar_field: P384Scalar,
+        digest: sha2::Digest384,
+        hash: sha2::sha384,
+        hmac: hmac_sha384,
+        scalar_bits: 384,
+        scalar_bytes: 48,
+        hash_bytes: 48,
+    );
+}
+
+/// ECDSA with P-521 and SHA-512.
+pub mod p521 {
+    use hacspec::prelude::*;
+
+    use crate::hmac::hmac_sha512;
+    use crate::p521::{self, P521Scalar};
+    use crate::sha2;
+
+    ecdsa_scheme!(
+        curve: p521,
+        scalar_field: P521Scalar,
+        digest: sha2::Digest512,
+        hash: sha2::sha512,
+        hmac: hmac_sha512,
+        scalar_bits: 521,
+        scalar_bytes: 66,
+        hash_bytes: 64,
+    );
+}
+
+/// ECDSA with secp256k1 and SHA-256.
+pub mod secp256k1 {
+    use hacspec::prelude::*;
+
+    use super::hmac_sha256;
+    use crate::secp256k1::{self, FieldElement, Secp256k1Scalar};
+    use crate::sha2;
+
+    ecdsa_scheme!(
+        curve: secp256k1,
+        scalar_field: Secp256k1Scalar,
+        digest: sha2::Digest,
+        hash: sha2::hash,
+        hmac: hmac_sha256,
+        scalar_bits: 256,
+        scalar_bytes: 32,
+        hash_bytes: 32,
+    );
+
+    /// The recovery id of a signature. Bit 0 is the parity of the
+    /// y-coordinate of R = k * G and bit 1 is set if the x-coordinate of R
+    /// is at least n.
+    pub type RecoveryId = u8;
+
+    /// Sign `msg` like `sign` and also return the recovery id.
+    pub fn sign_recoverable(
+        sk: Secp256k1Scalar,
+        msg: ByteSeq,
+        low_s: bool,
+    ) -> Result<(Signature, RecoveryId), String> {
+        let ((r, s), (x, y)) = sign_with_nonce_point(sk, msg)?;
+        // x >= n if and only if reducing it mod n changed it.
+        let x_reduced = FieldElement::from_byte_seq_be(r.to_byte_seq_be()) != x;
+        let mut recid: RecoveryId = 0;
+        if y.bit(0) {
+            recid += 1;
+        }
+        if x_reduced {
+            recid += 2;
+        }
+        if low_s && normalize_s((r, s)) != (r, s) {
+            // Negating s corresponds to negating R, which flips the parity
+            // of its y-coordinate.
+            Ok((normalize_s((r, s)), recid ^ 1))
+        } else {
+            Ok(((r, s), recid))
+        }
+    }
+
+    // Whether r + n is smaller than p, i.e. whether r < p - n. The inputs
+    // are public.
+    fn r_plus_n_fits(r: SerializedScalar) -> bool {
+        let p_minus_n =
+            ByteSeq::from("000000000000000000000000000000014551231950b75fc4402da1722fc9baee");
+        let mut result = false;
+        let mut done = false;
+        for i in 0..SCALAR_BYTES {
+            let a = r[i].declassify();
+            let b = p_minus_n[i].declassify();
+            if !done && a != b {
+                result = a < b;
+                done = true;
+            }
+        }
+        result
+    }
+
+    /// Recover the public key from the signature of `msg` and its recovery
+    /// id (SEC 1 Section 4.1.6).
+    /// The result is Q = r^-1 (s R - e G) where R is the point with
+    /// x-coordinate r (+ n) and the y parity given by `recid`.
+    pub fn recover_public_key(
+        msg: ByteSeq,
+        sig: Signature,
+        recid: RecoveryId,
+    ) -> Result<secp256k1::Affine, String> {
+        let (r, s) = sig;
+        if r == Secp256k1Scalar::from_literal(0) || s == Secp256k1Scalar::from_literal(0) {
+            return Err("Invalid signature".to_string());
+        }
+        if recid > 3 {
+            return Err("Invalid recovery id".to_string());
+        }
+        let r_bytes = scalar_to_be_bytes(r);
+        let x = if recid & 2 == 0 {
+            FieldElement::from_byte_seq_be(r_bytes)
+        } else {
+            if !r_plus_n_fits(r_bytes) {
+                return Err("Invalid recovery id".to_string());
+            }
+            FieldElement::from_byte_seq_be(r_bytes)
+                + FieldElement::from_hex(
+                    "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141",
+                )
+        };
+        let x_bytes = x.to_byte_seq_be();
+        let mut compressed = secp256k1::CompressedPoint::new();
+        compressed[0] = U8(0x02 + (recid & 1));
+        compressed = compressed.update(33 - x_bytes.len(), x_bytes);
+        let big_r = secp256k1::deserialize_compressed(compressed)?;
+
+        let e = hash_to_scalar(sha2::hash(msg));
+        let r_inv = r.inv();
+        let u1 = Secp256k1Scalar::from_literal(0) - e * r_inv;
+        let u2 = s * r_inv;
+        secp256k1::point_mul_add(to_canvas(u1), to_canvas(u2), big_r)
+    }
+}
@@ -0,0 +1,388 @@
+// Import hacspec and all needed definitions.
+use hacspec::prelude::*;
+
+// Import primitives
+use crate::curve25519::{FieldCanvas, FieldElement};
+use crate::sha2;
+
+unsigned_integer!(ScalarCanvas, 512);
+
+// Define the scalar field mod the group order
+// l = 2^252 + 27742317777372353535851937790883648493.
+// The canvas is large enough to reduce 64 byte hashes.
+field_integer!(
+    Scalar,
+    ScalarCanvas,
+    ScalarCanvas::from_hex("1000000000000000000000000000000014def9dea2f79cd65812631a5cf5d3ed")
+);
+
+// Extended twisted Edwards coordinates (X, Y, Z, T) on
+// -x^2 + y^2 = 1 + d x^2 y^2 with x = X/Z, y = Y/Z, and x * y = T/Z.
+pub type Point = (FieldElement, FieldElement, FieldElement, FieldElement);
+
+bytes!(CompressedPoint, 32);
+bytes!(SerializedScalar, 32);
+bytes!(SecretKey, 32);
+bytes!(Signature, 64);
+
+// d = -121665/121666
+pub fn curve_d() -> FieldElement {
+    FieldElement::from_hex("52036cee2b6ffe738cc740797779e89800700a4d4141d8ab75eb4dca135978a3")
+}
+
+// sqrt(-1) = 2^((p-1)/4)
+pub fn sqrt_m1() -> FieldElement {
+    FieldElement::from_hex("2b8324804fc1df0b2b4d00993dfbd7a72f431806ad2fe478c4ee1b274a0ea0b0")
+}
+
+/// x^e for a public exponent `e`.
+pub fn fe_pow(x: FieldElement, e: FieldCanvas) -> FieldElement {
+    let mut r = FieldElement::from_literal(1);
+    for i in 0..255 {
+        r = r * r;
+        if e.bit(254 - i) {
+            r = r * x;
+        }
+    }
+    r
+}
+
+pub fn fe_neg(x: FieldElement) -> FieldElement {
+    FieldElement::from_literal(0) - x
+}
+
+pub fn point_identity() -> Point {
+    (
+        FieldElement::from_literal(0),
+        FieldElement::from_literal(1),
+        FieldElement::from_literal(1),
+        FieldElement::from_literal(0),
+    )
+}
+
+pub fn base_point() -> Point {
+    let x =
+        FieldElement::from_hex("216936d3cd6e53fec0a4e231fdd6dc5c692cc7609525a7b2c9562d608f25d51a");
+    let y =
+        FieldElement::from_hex("6666666666666666666666666666666666666666666666666666666666666658");
+    (x, y, FieldElement::from_literal(1), x * y)
+}
+
+// The addition formulas are complete, i.e. they also work for doubling and
+// the identity.
+pub fn point_add(p: Point, q: Point) -> Point {
+    let (x1, y1, z1, t1) = p;
+    let (x2, y2, z2, t2) = q;
+    let two = FieldElement::from_literal(2);
+    let a = (y1 - x1) * (y2 - x2);
+    let b = (y1 + x1) * (y2 + x2);
+    let c = t1 * two * curve_d() * t2;
+    let d = z1 * two * z2;
+    let e = b - a;
+    let f = d - c;
+    let g = d + c;
+    let h = b + a;
+    (e * f, g * h, f * g, e * h)
+}
+
+pub fn point_double(p: Point) -> Point {
+    let (x1, y1, z1, _t1) = p;
+    let a = x1.pow(2);
+    let b = y1.pow(2);
+    let c = FieldElement::from_literal(2) * z1.pow(2);
+    let h = a + b;
+    let e = h - (x1 + y1).pow(2);
+    let g = a - b;
+    let f = c + g;
+    (e * f, g * h, f * g, e * h)
+}
+
+pub fn point_neg(p: Point) -> Point {
+    let (x, y, z, t) = p;
+    (fe_neg(x), y, z, fe_neg(t))
+}
+
+// Get bit `i` of the little-endian scalar `k` as 0 or 1.
+fn scalar_bit(k: SerializedScalar, i: usize) -> U8 {
+    (k[i / 8] >> (i % 8) as u32) & U8(1)
+}
+
+// Select `q` if `bit` is 1 and `p` if it is 0.
+// As in the curve25519 cswap the selection is done with field arithmetic
+// such that `bit` doesn't determine the control flow.
+fn point_select(bit: U8, p: Point, q: Point) -> Point {
+    let b = FieldElement::from_secret_literal(U128::from(bit));
+    let (x1, y1, z1, t1) = p;
+    let (x2, y2, z2, t2) = q;
+    (
+        x1 + b * (x2 - x1),
+        y1 + b * (y2 - y1),
+        z1 + b * (z2 - z1),
+        t1 + b * (t2 - t1),
+    )
+}
+
+// Double-and-always-add. The scalar may be secret, e.g. the secret key or
+// the nonce when signing, so its bits are never branched on.
+pub fn point_mul(s: Scalar, p: Point) -> Point {
+    let k = scalar_encode(s);
+    let mut q = point_identity();
+    for i in 0..256 {
+        q = point_double(q);
+        q = point_select(scalar_bit(k, 255 - i), q, point_add(q, p));
+    }
+    q
+}
+
+pub fn point_mul_base(s: Scalar) -> Point {
+    point_mul(s, base_point())
+}
+
+pub fn point_mul_by_cofactor(p: Point) -> Point {
+    point_double(point_double(point_double(p)))
+}
+
+pub fn point_eq(p: Point, q: Point) -> bool {
+    let (x1, y1, z1, _) = p;
+    let (x2, y2, z2, _) = q;
+    x1 * z2 == x2 * z1 && y1 * z2 == y2 * z1
+}
+
+pub fn point_compress(p: Point) -> CompressedPoint {
+    let (x, y, z, _) = p;
+    let z_inv = z.inv();
+    let x = x * z_inv;
+    let y = y * z_inv;
+    let mut s = CompressedPoint::copy_pad(y.to_byte_seq_le());
+    if x.bit(0) {
+        s[31] |= U8(0x80);
+    }
+    s
+}
+
+/// Decode a point as in RFC 8032 Section 5.1.3.
+pub fn point_decompress(s: CompressedPoint) -> Result<Point, String> {
+    let x_0 = (s[31] >> 7).declassify() == 1;
+    let mut y_s = s;
+    y_s[31] &= U8(0x7f);
+    let y = FieldElement::from_byte_seq_le(y_s);
+    if CompressedPoint::copy_pad(y.to_byte_seq_le()) != y_s {
+        return Err("Point encoding is not canonical".to_string());
+    }
+
+    // x = (u/v)^((p+3)/8) = u v^3 (u v^7)^((p-5)/8)
+    let u = y.pow(2) - FieldElement::from_literal(1);
+    let v = curve_d() * y.pow(2) + FieldElement::from_literal(1);
+    let p58 =
+        FieldCanvas::from_hex("0ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffd");
+    let mut x = u * v.pow(3) * fe_pow(u * v.pow(7), p58);
+    let vxx = v * x.pow(2);
+    if vxx == fe_neg(u) {
+        x = x * sqrt_m1();
+    } else if vxx != u {
+        return Err("Point is not on the curve".to_string());
+    }
+    if x == FieldElement::from_literal(0) && x_0 {
+        return Err("Invalid sign for x = 0".to_string());
+    }
+    if x.bit(0) != x_0 {
+        x = fe_neg(x);
+    }
+    Ok((x, y, FieldElement::from_literal(1), x * y))
+}
+
+pub fn scalar_from_hash(h: ByteSeq) -> Scalar {
+    Scalar::from_byte_seq_le(sha2::sha512(h))
+}
+
+pub fn scalar_encode(s: Scalar) -> SerializedScalar {
+    SerializedScalar::copy_pad(s.to_byte_seq_le())
+}
+
+/// Decode a scalar and fail if it is not fully reduced mod l.
+pub fn scalar_decode(s: SerializedScalar) -> Result<Scalar, String> {
+    let s_ = Scalar::from_byte_seq_le(s);
+    if scalar_encode(s_) != s {
+        return Err("Scalar is not reduced".to_string());
+    }
+    Ok(s_)
+}
+
+fn clamp(s: SerializedScalar) -> SerializedScalar {
+    let mut k = s;
+    k[0] &= U8(248);
+    k[31] &= U8(127);
+    k[31] |= U8(64);
+    k
+}
+
+/// Expand the secret key into the secret scalar and the prefix for the nonce.
+pub fn secret_expand(sk: SecretKey) -> (Scalar, SerializedScalar) {
+    let h = sha2::sha512(ByteSeq::from(sk.raw()));
+    let s = clamp(SerializedScalar::from_sub(h, 0..32));
+    let prefix = SerializedScalar::from_sub(h, 32..64);
+    (Scalar::from_byte_seq_le(s), prefix)
+}
+
+pub fn secret_to_public(sk: SecretKey) -> CompressedPoint {
+    let (s, _) = secret_expand(sk);
+    point_compress(point_mul_base(s))
+}
+
+// dom2(x, y) from RFC 8032 Section 5.1.
+// It is empty for pure Ed25519.
+fn dom2(phflag: u8, ctx: ByteSeq) -> Result<ByteSeq, String> {
+    if ctx.len() > 255 {
+        return Err("Context is longer than 255 bytes".to_string());
+    }
+    let prefix = ByteSeq::from("53696745643235353139206e6f204564323535313920636f6c6c6973696f6e73");
+    let mut out = ByteSeq::new(prefix.len() + 2 + ctx.len());
+    out = out.update(0, prefix.clone());
+    out = out.update_element(prefix.len(), U8(phflag));
+    out = out.update_element(prefix.len() + 1, U8(ctx.len() as u8));
+    out = out.update(prefix.len() + 2, ctx);
+    Ok(out)
+}
+
+fn hash_r_a_m(dom: ByteSeq, r: CompressedPoint, a: CompressedPoint, msg: ByteSeq) -> Scalar {
+    let dom_len = dom.len();
+    let mut k_in = ByteSeq::new(dom_len + 64 + msg.len());
+    k_in = k_in.update(0, dom);
+    k_in = k_in.update(dom_len, r);
+    k_in = k_in.update(dom_len + 32, a);
+    k_in = k_in.update(dom_len + 64, msg);
+    scalar_from_hash(k_in)
+}
+
+fn sign_with_dom(sk: SecretKey, dom: ByteSeq, msg: ByteSeq) -> Signature {
+    let (s, prefix) = secret_expand(sk);
+    let a = point_compress(point_mul_base(s));
+
+    let dom_len = dom.len();
+    let mut r_in = ByteSeq::new(dom_len + 32 + msg.len());
+    r_in = r_in.update(0, dom.clone());
+    r_in = r_in.update(dom_len, prefix);
+    r_in = r_in.update(dom_len + 32, msg.clone());
+    let r = scalar_from_hash(r_in);
+    let r_enc = point_compress(point_mul_base(r));
+
+    let k = hash_r_a_m(dom, r_enc, a, msg);
+    let s_sig = r + k * s;
+
+    let mut sig = Signature::new();
+    sig = sig.update(0, r_enc);
+    sig = sig.update(32, scalar_encode(s_sig));
+    sig
+}
+
+pub fn sign(sk: SecretKey, msg: ByteSeq) -> Signature {
+    sign_with_dom(sk, ByteSeq::new(0), msg)
+}
+
+/// Ed25519ctx with a context of 1 to 255 bytes.
+pub fn sign_ctx(sk: SecretKey, ctx: ByteSeq, msg: ByteSeq) -> Result<Signature, String> {
+    if ctx.len() == 0 {
+        return Err("Ed25519ctx requires a non-empty context".to_string());
+    }
+    Ok(sign_with_dom(sk, dom2(0, ctx)?, msg))
+}
+
+/// Ed25519ph on the SHA-512 hash `ph` of the message.
+/// This allows hashing large messages before signing them.
+pub fn sign_prehashed(
+    sk: SecretKey,
+    ctx: ByteSeq,
+    ph: sha2::Digest512,
+) -> Result<Signature, String> {
+    Ok(sign_with_dom(sk, dom2(1, ctx)?, ByteSeq::from(ph.raw())))
+}
+
+/// Ed25519ph with a context of at most 255 bytes.
+pub fn sign_ph(sk: SecretKey, ctx: ByteSeq, msg: ByteSeq) -> Result<Signature, String> {
+    sign_prehashed(sk, ctx, sha2::sha512(msg))
+}
+
+// Decode the signature and public key and return [S]B and R + [k]A.
+fn verify_points(
+    pk: CompressedPoint,
+    dom: ByteSeq,
+    msg: ByteSeq,
+    sig: Signature,
+) -> Result<(Point, Point), String> {
+    let a = point_decompress(pk)?;
+    let r_enc = CompressedPoint::from_sub(sig, 0..32);
+    let r = point_decompress(r_enc)?;
+    let s = scalar_decode(SerializedScalar::from_sub(sig, 32..64))?;
+    let k = hash_r_a_m(dom, r_enc, pk, msg);
+    Ok((point_mul_base(s), point_add(r, point_mul(k, a))))
+}
+
+fn verify_with_dom(
+    pk: CompressedPoint,
+    dom: ByteSeq,
+    msg: ByteSeq,
+    sig: Signature,
+) -> Result<(), String> {
+    let (sb, rka) = verify_points(pk, dom, msg, sig)?;
+    if point_eq(point_mul_by_cofactor(sb), point_mul_by_cofactor(rka)) {
+        Ok(())
+    } else {
+        Err("Signature verification failed".to_string())
+    }
+}
+
+/// Verify a signature with the cofactored equation [8][S]B = [8]R + [8][k]A.
+///
+/// RFC 8032 allows checking either this or the cofactorless [S]B = R + [k]A.
+/// Both agree on all honestly generated signatures, but they differ on
+/// signatures where R or A have a small order component.
+/// Implementations that have to agree on the validity of every signature,
+/// e.g. in consensus protocols, must all use the same equation.
+pub fn verify(pk: CompressedPoint, msg: ByteSeq, sig: Signature) -> Result<(), String> {
+    verify_with_dom(pk, ByteSeq::new(0), msg, sig)
+}
+
+/// Verify a signature with the cofactorless equation [S]B = R + [k]A.
+/// See `verify` for the difference.
+pub fn verify_cofactorless(
+    pk: CompressedPoint,
+    msg: ByteSeq,
+    sig: Signature,
+) -> Result<(), String> {
+    let (sb, rka) = verify_points(pk, ByteSeq::new(0), msg, sig)?;
+    if point_eq(sb, rka) {
+        Ok(())
+    } else {
+        Err("Signature verification failed".to_string())
+    }
+}
+
+pub fn verify_ctx(
+    pk: CompressedPoint,
+    ctx: ByteSeq,
+    msg: ByteSeq,
+    sig: Signature,
+) -> Result<(), String> {
+    if ctx.len() == 0 {
+        return Err("Ed25519ctx requires a non-empty context".to_string());
+    }
+    verify_with_dom(pk, dom2(0, ctx)?, msg, sig)
+}
+
+pub fn verify_prehashed(
+    pk: CompressedPoint,
+    ctx: ByteSeq,
+    ph: sha2::Digest512,
+    sig: Signature,
+) -> Result<(), String> {
+    verify_with_dom(pk, dom2(1, ctx)?, ByteSeq::from(ph.raw()), sig)
+}
+
+pub fn verify_ph(
+    pk: CompressedPoint,
+    ctx: ByteSeq,
+    msg: ByteSeq,
+    sig: Signature,
+) -> Result<(), String> {
+    verify_prehashed(pk, ctx, sha2::sha512(msg), sig)
+}
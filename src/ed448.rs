@@ -0,0 +1,148 @@
+// Import hacspec and all needed definitions.
+use hacspec::prelude::*;
+
+// Import primitives
+use crate::edwards448::*;
+use crate::fips202;
+
+bytes!(SecretKey, 57);
+bytes!(Signature, 114);
+bytes!(PreHash, 64);
+
+fn scalar_from_hash(h: ByteSeq) -> Scalar {
+    Scalar::from_byte_seq_le(fips202::shake256(h, 114))
+}
+
+fn clamp(s: SerializedScalar) -> SerializedScalar {
+    let mut k = s;
+    k[0] &= U8(252);
+    k[55] |= U8(128);
+    k[56] = U8(0);
+    k
+}
+
+/// Expand the secret key into the secret scalar and the prefix for the nonce.
+pub fn secret_expand(sk: SecretKey) -> (Scalar, SerializedScalar) {
+    let h = fips202::shake256(ByteSeq::from(sk.raw()), 114);
+    let s = clamp(SerializedScalar::from(h.sub(0, 57)));
+    let prefix = SerializedScalar::from(h.sub(57, 57));
+    (Scalar::from_byte_seq_le(s), prefix)
+}
+
+pub fn secret_to_public(sk: SecretKey) -> CompressedPoint {
+    let (s, _) = secret_expand(sk);
+    point_compress(point_mul_base(s))
+}
+
+// dom4(x, y) from RFC 8032 Section 5.2.
+// Unlike dom2 for Ed25519 it is also used for pure Ed448.
+fn dom4(phflag: u8, ctx: ByteSeq) -> Result<ByteSeq, String> {
+    if ctx.len() > 255 {
+        return Err("Context is longer than 255 bytes".to_string());
+    }
+    let prefix = ByteSeq::from("5369674564343438");
+    let mut out = ByteSeq::new(prefix.len() + 2 + ctx.len());
+    out = out.update(0, prefix.clone());
+    out = out.update_element(prefix.len(), U8(phflag));
+    out = out.update_element(prefix.len() + 1, U8(ctx.len() as u8));
+    out = out.update(prefix.len() + 2, ctx);
+    Ok(out)
+}
+
+fn hash_r_a_m(dom: ByteSeq, r: CompressedPoint, a: CompressedPoint, msg: ByteSeq) -> Scalar {
+    let dom_len = dom.len();
+    let mut k_in = ByteSeq::new(dom_len + 114 + msg.len());
+    k_in = k_in.update(0, dom);
+    k_in = k_in.update(dom_len, r);
+    k_in = k_in.update(dom_len + 57, a);
+    k_in = k_in.update(dom_len + 114, msg);
+    scalar_from_hash(k_in)
+}
+
+fn sign_with_dom(sk: SecretKey, dom: ByteSeq, msg: ByteSeq) -> Signature {
+    let (s, prefix) = secret_expand(sk);
+    let a = point_compress(point_mul_base(s));
+
+    let dom_len = dom.len();
+    let mut r_in = ByteSeq::new(dom_len + 57 + msg.len());
+    r_in = r_in.update(0, dom.clone());
+    r_in = r_in.update(dom_len, prefix);
+    r_in = r_in.update(dom_len + 57, msg.clone());
+    let r = scalar_from_hash(r_in);
+    let r_enc = point_compress(point_mul_base(r));
+
+    let k = hash_r_a_m(dom, r_enc, a, msg);
+    let s_sig = r + k * s;
+
+    let mut sig = Signature::new();
+    sig = sig.update(0, r_enc);
+    sig = sig.update(57, scalar_encode(s_sig));
+    sig
+}
+
+/// Ed448 with a context of at most 255 bytes, which may be empty.
+pub fn sign(sk: SecretKey, ctx: ByteSeq, msg: ByteSeq) -> Result<Signature, String> {
+    Ok(sign_with_dom(sk, dom4(0, ctx)?, msg))
+}
+
+/// Ed448ph on the 64 byte SHAKE256 hash `ph` of the message.
+pub fn sign_prehashed(sk: SecretKey, ctx: ByteSeq, ph: PreHash) -> Result<Signature, String> {
+    Ok(sign_with_dom(sk, dom4(1, ctx)?, ByteSeq::from(ph.raw())))
+}
+
+pub fn prehash(msg: ByteSeq) -> PreHash {
+    PreHash::from(fips202::shake256(msg, 64))
+}
+
+pub fn sign_ph(sk: SecretKey, ctx: ByteSeq, msg: ByteSeq) -> Result<Signature, String> {
+    sign_prehashed(sk, ctx, prehash(msg))
+}
+
+// Verify with the cofactored equation [4][S]B = [4]R + [4][k]A.
+// See `ed25519::verify` for the difference to the cofactorless equation.
+fn verify_with_dom(
+    pk: CompressedPoint,
+    dom: ByteSeq,
+    msg: ByteSeq,
+    sig: Signature,
+) -> Result<(), String> {
+    let a = point_decompress(pk)?;
+    let r_enc = CompressedPoint::from_sub(sig, 0..57);
+    let r = point_decompress(r_enc)?;
+    let s = scalar_decode(SerializedScalar::from_sub(sig, 57..114))?;
+    let k = hash_r_a_m(dom, r_enc, pk, msg);
+    let sb = point_mul_base(s);
+    let rka = point_add(r, point_mul(k, a));
+    if point_eq(point_mul_by_cofactor(sb), point_mul_by_cofactor(rka)) {
+        Ok(())
+    } else {
+        Err("Signature verification failed".to_string())
+    }
+}
+
+pub fn verify(
+    pk: CompressedPoint,
+    ctx: ByteSeq,
+    msg: ByteSeq,
+    sig: Signature,
+) -> Result<(), String> {
+    verify_with_dom(pk, dom4(0, ctx)?, msg, sig)
+}
+
+pub fn verify_prehashed(
+    pk: CompressedPoint,
+    ctx: ByteSeq,
+    ph: PreHash,
+    sig: Signature,
+) -> Result<(), String> {
+    verify_with_dom(pk, dom4(1, ctx)?, ByteSeq::from(ph.raw()), sig)
+}
+
+pub fn verify_ph(
+    pk: CompressedPoint,
+    ctx: ByteSeq,
+    msg: ByteSeq,
+    sig: Signature,
+) -> Result<(), String> {
+    verify_prehashed(pk, ctx, prehash(msg), sig)
+}
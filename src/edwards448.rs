@@ -0,0 +1,171 @@
+// Import hacspec and all needed definitions.
+use hacspec::prelude::*;
+
+// Import primitives
+use crate::curve448::{FieldCanvas, FieldElement};
+
+unsigned_integer!(ScalarCanvas, 912);
+
+// Define the scalar field mod the group order
+// l = 2^446 - 13818066809895115352007386748515426880336692474882178609894547503885.
+// The canvas is large enough to reduce 114 byte hashes.
+field_integer!(
+    Scalar,
+    ScalarCanvas,
+    ScalarCanvas::from_hex("3fffffffffffffffffffffffffffffffffffffffffffffffffffffff7cca23e9c44edb49aed63690216cc2728dc58f552378c292ab5844f3")
+);
+
+// Projective Edwards coordinates (X, Y, Z) on the untwisted curve
+// x^2 + y^2 = 1 + d x^2 y^2 with x = X/Z and y = Y/Z.
+pub type Point = (FieldElement, FieldElement, FieldElement);
+
+bytes!(CompressedPoint, 57);
+bytes!(SerializedScalar, 57);
+
+// d = -39081
+pub fn curve_d() -> FieldElement {
+    FieldElement::from_literal(0) - FieldElement::from_literal(39081)
+}
+
+/// x^e for a public exponent `e`.
+pub fn fe_pow(x: FieldElement, e: FieldCanvas) -> FieldElement {
+    let mut r = FieldElement::from_literal(1);
+    for i in 0..448 {
+        r = r * r;
+        if e.bit(447 - i) {
+            r = r * x;
+        }
+    }
+    r
+}
+
+pub fn fe_neg(x: FieldElement) -> FieldElement {
+    FieldElement::from_literal(0) - x
+}
+
+pub fn point_identity() -> Point {
+    (
+        FieldElement::from_literal(0),
+        FieldElement::from_literal(1),
+        FieldElement::from_literal(1),
+    )
+}
+
+pub fn base_point() -> Point {
+    let x = FieldElement::from_hex("4f1970c66bed0ded221d15a622bf36da9e146570470f1767ea6de324a3d3a46412ae1af72ab66511433b80e18b00938e2626a82bc70cc05e");
+    let y = FieldElement::from_hex("693f46716eb6bc248876203756c9c7624bea73736ca3984087789c1e05a0c2d73ad3ff1ce67c39c4fdbd132c4ed7c8ad9808795bf230fa14");
+    (x, y, FieldElement::from_literal(1))
+}
+
+// RFC 8032 Section 5.2.4.
+// Because d is not a square the addition formulas are complete, i.e. they
+// also work for doubling and the identity.
+pub fn point_add(p: Point, q: Point) -> Point {
+    let (x1, y1, z1) = p;
+    let (x2, y2, z2) = q;
+    let a = z1 * z2;
+    let b = a.pow(2);
+    let c = x1 * x2;
+    let d = y1 * y2;
+    let e = curve_d() * c * d;
+    let f = b - e;
+    let g = b + e;
+    let h = (x1 + y1) * (x2 + y2);
+    (a * f * (h - c - d), a * g * (d - c), f * g)
+}
+
+pub fn point_double(p: Point) -> Point {
+    let (x1, y1, z1) = p;
+    let b = (x1 + y1).pow(2);
+    let c = x1.pow(2);
+    let d = y1.pow(2);
+    let e = c + d;
+    let h = z1.pow(2);
+    let j = e - FieldElement::from_literal(2) * h;
+    ((b - e) * j, e * (c - d), e * j)
+}
+
+pub fn point_neg(p: Point) -> Point {
+    let (x, y, z) = p;
+    (fe_neg(x), y, z)
+}
+
+pub fn point_mul(s: Scalar, p: Point) -> Point {
+    let mut q = point_identity();
+    for i in 0..448 {
+        q = point_double(q);
+        if s.bit(447 - i) {
+            q = point_add(q, p);
+        }
+    }
+    q
+}
+
+pub fn point_mul_base(s: Scalar) -> Point {
+    point_mul(s, base_point())
+}
+
+pub fn point_mul_by_cofactor(p: Point) -> Point {
+    point_double(point_double(p))
+}
+
+pub fn point_eq(p: Point, q: Point) -> bool {
+    let (x1, y1, z1) = p;
+    let (x2, y2, z2) = q;
+    x1 * z2 == x2 * z1 && y1 * z2 == y2 * z1
+}
+
+pub fn point_compress(p: Point) -> CompressedPoint {
+    let (x, y, z) = p;
+    let z_inv = z.inv();
+    let x = x * z_inv;
+    let y = y * z_inv;
+    let mut s = CompressedPoint::copy_pad(y.to_byte_seq_le());
+    if x.bit(0) {
+        s[56] |= U8(0x80);
+    }
+    s
+}
+
+/// Decode a point as in RFC 8032 Section 5.2.3.
+pub fn point_decompress(s: CompressedPoint) -> Result<Point, String> {
+    let x_0 = (s[56] >> 7).declassify() == 1;
+    if (s[56] & U8(0x7f)).declassify() != 0 {
+        return Err("Point encoding is not canonical".to_string());
+    }
+    let mut y_s = s;
+    y_s[56] = U8(0);
+    let y = FieldElement::from_byte_seq_le(y_s);
+    if CompressedPoint::copy_pad(y.to_byte_seq_le()) != y_s {
+        return Err("Point encoding is not canonical".to_string());
+    }
+
+    // x = (u/v)^((p+1)/4) = u^3 v (u^5 v^3)^((p-3)/4)
+    let u = y.pow(2) - FieldElement::from_literal(1);
+    let v = curve_d() * y.pow(2) - FieldElement::from_literal(1);
+    let p34 = FieldCanvas::from_hex("3fffffffffffffffffffffffffffffffffffffffffffffffffffffffbfffffffffffffffffffffffffffffffffffffffffffffffffffffff");
+    let mut x = u.pow(3) * v * fe_pow(u.pow(5) * v.pow(3), p34);
+    if v * x.pow(2) != u {
+        return Err("Point is not on the curve".to_string());
+    }
+    if x == FieldElement::from_literal(0) && x_0 {
+        return Err("Invalid sign for x = 0".to_string());
+    }
+    if x.bit(0) != x_0 {
+        x = fe_neg(x);
+    }
+    Ok((x, y, FieldElement::from_literal(1)))
+}
+
+pub fn scalar_encode(s: Scalar) -> SerializedScalar {
+    SerializedScalar::copy_pad(s.to_byte_seq_le())
+}
+
+/// Decode a scalar and fail if it is not fully reduced mod l.
+pub fn scalar_decode(s: SerializedScalar) -> Result<Scalar, String> {
+    let s_ = Scalar::from_byte_seq_le(s);
+    if scalar_encode(s_) != s {
+        return Err("Scalar is not reduced".to_string());
+    }
+    Ok(s_)
+}
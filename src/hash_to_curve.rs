@@ -0,0 +1,77 @@
+// Import hacspec and all needed definitions.
+use hacspec::prelude::*;
+
+// Import primitives
+use crate::sha2;
+
+// expand_message_xmd from RFC 9380 Section 5.3.1.
+// `hash` has an output of `b_in_bytes` and a block size of `s_in_bytes`.
+fn expand_message_xmd(
+    hash: fn(ByteSeq) -> ByteSeq,
+    b_in_bytes: usize,
+    s_in_bytes: usize,
+    msg: ByteSeq,
+    dst: ByteSeq,
+    len_in_bytes: usize,
+) -> Result<ByteSeq, String> {
+    let ell = div_ceil(len_in_bytes, b_in_bytes);
+    if ell > 255 || len_in_bytes > 65535 {
+        return Err("Requested output is too long".to_string());
+    }
+    if dst.len() > 255 {
+        return Err("DST is longer than 255 bytes".to_string());
+    }
+    let mut dst_prime = ByteSeq::new(dst.len() + 1);
+    dst_prime = dst_prime.update(0, dst.clone());
+    dst_prime = dst_prime.update_element(dst.len(), U8(dst.len() as u8));
+
+    // msg_prime = Z_pad || msg || I2OSP(len_in_bytes, 2) || 0x00 || DST_prime
+    let mut msg_prime = ByteSeq::new(s_in_bytes + msg.len() + 3 + dst_prime.len());
+    msg_prime = msg_prime.update(s_in_bytes, msg.clone());
+    msg_prime = msg_prime.update_element(s_in_bytes + msg.len(), U8((len_in_bytes >> 8) as u8));
+    msg_prime = msg_prime.update_element(s_in_bytes + msg.len() + 1, U8(len_in_bytes as u8));
+    msg_prime = msg_prime.update(s_in_bytes + msg.len() + 3, dst_prime.clone());
+    let b_0 = hash(msg_prime);
+
+    let mut uniform_bytes = ByteSeq::new(ell * b_in_bytes);
+    let mut b_i = ByteSeq::new(b_in_bytes);
+    for i in 1..ell + 1 {
+        // b_1 = H(b_0 || 0x01 || DST_prime)
+        // b_i = H(strxor(b_0, b_(i - 1)) || I2OSP(i, 1) || DST_prime)
+        let mut b_in = ByteSeq::new(b_in_bytes + 1 + dst_prime.len());
+        for j in 0..b_in_bytes {
+            b_in[j] = b_0[j] ^ b_i[j];
+        }
+        b_in = b_in.update_element(b_in_bytes, U8(i as u8));
+        b_in = b_in.update(b_in_bytes + 1, dst_prime.clone());
+        b_i = hash(b_in);
+        uniform_bytes = uniform_bytes.update((i - 1) * b_in_bytes, b_i.clone());
+    }
+    Ok(uniform_bytes.sub(0, len_in_bytes))
+}
+
+fn sha512(msg: ByteSeq) -> ByteSeq {
+    ByteSeq::from(sha2::sha512(msg).raw())
+}
+
+/// expand_message_xmd with SHA-512.
+pub fn expand_message_xmd_sha512(
+    msg: ByteSeq,
+    dst: ByteSeq,
+    len_in_bytes: usize,
+) -> Result<ByteSeq, String> {
+    expand_message_xmd(sha512, 64, 128, msg, dst, len_in_bytes)
+}
+
+fn sha256(msg: ByteSeq) -> ByteSeq {
+    ByteSeq::from(sha2::hash(msg).raw())
+}
+
+/// expand_message_xmd with SHA-256.
+pub fn expand_message_xmd_sha256(
+    msg: ByteSeq,
+    dst: ByteSeq,
+    len_in_bytes: usize,
+) -> Result<ByteSeq, String> {
+    expand_message_xmd(sha256, 32, 64, msg, dst, len_in_bytes)
+}
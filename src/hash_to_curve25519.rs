@@ -0,0 +1,167 @@
+// Import hacspec and all needed definitions.
+use hacspec::prelude::*;
+
+// Import primitives
+use crate::curve25519::{FieldCanvas, FieldElement};
+use crate::ed25519::{self, fe_neg, fe_pow, sqrt_m1};
+use crate::hash_to_curve::expand_message_xmd_sha512;
+
+// Hashing to curve25519 and edwards25519 from RFC 9380 with the suites
+//   curve25519_XMD:SHA-512_ELL2_RO_ and curve25519_XMD:SHA-512_ELL2_NU_
+//   edwards25519_XMD:SHA-512_ELL2_RO_ and edwards25519_XMD:SHA-512_ELL2_NU_
+// All suites use Elligator 2 on curve25519. The edwards25519 suites use the
+// rational map to edwards25519. Cofactor clearing is done on edwards25519
+// for both.
+
+// Affine coordinates (u, v) on the Montgomery curve v^2 = u^3 + A u^2 + u.
+pub type MontgomeryPoint = (FieldElement, FieldElement);
+
+// L = ceil((ceil(log2(p)) + k) / 8) with k = 128
+const L: usize = 48;
+
+fn curve_a() -> FieldElement {
+    FieldElement::from_literal(486662)
+}
+
+// sqrt(-486664) with sgn0 equal to 0
+fn sqrt_minus_a_minus_two() -> FieldElement {
+    FieldElement::from_hex("0f26edf460a006bbd27b08dc03fc4f7ec5a1d3d14b7d1a82cc6e04aaff457e06")
+}
+
+fn sgn0(x: FieldElement) -> bool {
+    x.bit(0)
+}
+
+// inv0(0) = 0
+fn inv0(x: FieldElement) -> FieldElement {
+    if x == FieldElement::from_literal(0) {
+        x
+    } else {
+        x.inv()
+    }
+}
+
+fn is_square(x: FieldElement) -> bool {
+    let p12 =
+        FieldCanvas::from_hex("3ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff6");
+    let r = fe_pow(x, p12);
+    r == FieldElement::from_literal(0) || r == FieldElement::from_literal(1)
+}
+
+// sqrt for p = 5 mod 8. The input must be square.
+fn sqrt(x: FieldElement) -> FieldElement {
+    let p38 =
+        FieldCanvas::from_hex("0ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffe");
+    let r = fe_pow(x, p38);
+    if r.pow(2) == x {
+        r
+    } else {
+        r * sqrt_m1()
+    }
+}
+
+// OS2IP(b) mod p for 48 bytes b.
+fn field_element_from_be_bytes(b: ByteSeq) -> FieldElement {
+    let two_128: FieldElement = FieldCanvas::pow2(128).into();
+    let mut r = FieldElement::from_literal(0);
+    for i in 0..3 {
+        let mut word = U128Word::new();
+        word = word.update_sub(0, b.clone(), 16 * i, 16);
+        r = r * two_128 + FieldElement::from_secret_literal(u128_from_be_bytes(word));
+    }
+    r
+}
+
+/// hash_to_field from RFC 9380 Section 5.2 with m = 1 and count = 2.
+pub fn hash_to_field(msg: ByteSeq, dst: ByteSeq) -> Result<(FieldElement, FieldElement), String> {
+    let uniform_bytes = expand_message_xmd_sha512(msg, dst, 2 * L)?;
+    Ok((
+        field_element_from_be_bytes(uniform_bytes.sub(0, L)),
+        field_element_from_be_bytes(uniform_bytes.sub(L, L)),
+    ))
+}
+
+/// hash_to_field from RFC 9380 Section 5.2 with m = 1 and count = 1.
+pub fn hash_to_field_single(msg: ByteSeq, dst: ByteSeq) -> Result<FieldElement, String> {
+    let uniform_bytes = expand_message_xmd_sha512(msg, dst, L)?;
+    Ok(field_element_from_be_bytes(uniform_bytes))
+}
+
+/// The Elligator 2 map to curve25519 from RFC 9380 Section 6.7.1 with Z = 2.
+pub fn map_to_curve_elligator2(u: FieldElement) -> MontgomeryPoint {
+    let one = FieldElement::from_literal(1);
+    let a = curve_a();
+    let z = FieldElement::from_literal(2);
+    // x1 = -A / (1 + Z * u^2), or -A if the denominator is 0
+    let x1 = fe_neg(a) * inv0(one + z * u.pow(2));
+    let x1 = if x1 == FieldElement::from_literal(0) {
+        fe_neg(a)
+    } else {
+        x1
+    };
+    let gx1 = x1.pow(3) + a * x1.pow(2) + x1;
+    let x2 = fe_neg(x1) - a;
+    let gx2 = x2.pow(3) + a * x2.pow(2) + x2;
+    if is_square(gx1) {
+        let y = sqrt(gx1);
+        (x1, if sgn0(y) { y } else { fe_neg(y) })
+    } else {
+        let y = sqrt(gx2);
+        (x2, if sgn0(y) { fe_neg(y) } else { y })
+    }
+}
+
+/// The rational map from curve25519 to edwards25519 (RFC 9380 Appendix D).
+pub fn montgomery_to_edwards(p: MontgomeryPoint) -> ed25519::Point {
+    let (s, t) = p;
+    let one = FieldElement::from_literal(1);
+    if t == FieldElement::from_literal(0) || s + one == FieldElement::from_literal(0) {
+        return ed25519::point_identity();
+    }
+    let v = sqrt_minus_a_minus_two() * s * t.inv();
+    let w = (s - one) * (s + one).inv();
+    (v, w, one, v * w)
+}
+
+/// The inverse of `montgomery_to_edwards`.
+pub fn edwards_to_montgomery(p: ed25519::Point) -> MontgomeryPoint {
+    let (x, y, z, _) = p;
+    let z_inv = z.inv();
+    let x = x * z_inv;
+    let y = y * z_inv;
+    let one = FieldElement::from_literal(1);
+    let u = (one + y) * inv0(one - y);
+    let v = sqrt_minus_a_minus_two() * u * inv0(x);
+    (u, v)
+}
+
+fn map_to_edwards25519(u: FieldElement) -> ed25519::Point {
+    montgomery_to_edwards(map_to_curve_elligator2(u))
+}
+
+/// edwards25519_XMD:SHA-512_ELL2_RO_
+pub fn edwards25519_hash_to_curve(msg: ByteSeq, dst: ByteSeq) -> Result<ed25519::Point, String> {
+    let (u0, u1) = hash_to_field(msg, dst)?;
+    let q0 = map_to_edwards25519(u0);
+    let q1 = map_to_edwards25519(u1);
+    Ok(ed25519::point_mul_by_cofactor(ed25519::point_add(q0, q1)))
+}
+
+/// edwards25519_XMD:SHA-512_ELL2_NU_
+/// The output is not uniformly distributed, see RFC 9380 Section 3.
+pub fn edwards25519_encode_to_curve(msg: ByteSeq, dst: ByteSeq) -> Result<ed25519::Point, String> {
+    let u = hash_to_field_single(msg, dst)?;
+    Ok(ed25519::point_mul_by_cofactor(map_to_edwards25519(u)))
+}
+
+/// curve25519_XMD:SHA-512_ELL2_RO_
+pub fn curve25519_hash_to_curve(msg: ByteSeq, dst: ByteSeq) -> Result<MontgomeryPoint, String> {
+    Ok(edwards_to_montgomery(edwards25519_hash_to_curve(msg, dst)?))
+}
+
+/// curve25519_XMD:SHA-512_ELL2_NU_
+pub fn curve25519_encode_to_curve(msg: ByteSeq, dst: ByteSeq) -> Result<MontgomeryPoint, String> {
+    Ok(edwards_to_montgomery(edwards25519_encode_to_curve(
+        msg, dst,
+    )?))
+}
@@ -0,0 +1,126 @@
+// Import hacspec and all needed definitions.
+use hacspec::prelude::*;
+
+// Import primitives
+use crate::hash_to_curve::expand_message_xmd_sha256;
+use crate::p256::{self, Affine, FieldCanvas, FieldElement};
+
+// Hashing to P-256 from RFC 9380 with the suites
+//   P256_XMD:SHA-256_SSWU_RO_ and P256_XMD:SHA-256_SSWU_NU_
+// Both use the simplified SWU map (Section 6.6.2) directly on P-256 since
+// a and b are both non-zero. The cofactor is 1.
+
+// L = ceil((ceil(log2(p)) + k) / 8) with k = 128
+const L: usize = 48;
+
+fn curve_a() -> FieldElement {
+    FieldElement::from_literal(0) - FieldElement::from_literal(3)
+}
+
+// Z = -10
+fn sswu_z() -> FieldElement {
+    FieldElement::from_literal(0) - FieldElement::from_literal(10)
+}
+
+fn sgn0(x: FieldElement) -> bool {
+    x.bit(0)
+}
+
+// inv0(0) = 0
+fn inv0(x: FieldElement) -> FieldElement {
+    if x == FieldElement::from_literal(0) {
+        x
+    } else {
+        x.inv()
+    }
+}
+
+fn is_square(x: FieldElement) -> bool {
+    let p12 =
+        FieldCanvas::from_hex("7fffffff800000008000000000000000000000007fffffffffffffffffffffff");
+    let r = p256::fe_pow(x, p12);
+    r == FieldElement::from_literal(0) || r == FieldElement::from_literal(1)
+}
+
+// sqrt for p = 3 mod 4. The input must be square.
+fn sqrt(x: FieldElement) -> FieldElement {
+    let p14 =
+        FieldCanvas::from_hex("3fffffffc0000000400000000000000000000000400000000000000000000000");
+    p256::fe_pow(x, p14)
+}
+
+// OS2IP(b) mod p for 48 bytes b.
+fn field_element_from_be_bytes(b: ByteSeq) -> FieldElement {
+    let two_128: FieldElement = FieldCanvas::pow2(128).into();
+    let mut r = FieldElement::from_literal(0);
+    for i in 0..3 {
+        let mut word = U128Word::new();
+        word = word.update_sub(0, b.clone(), 16 * i, 16);
+        r = r * two_128 + FieldElement::from_secret_literal(u128_from_be_bytes(word));
+    }
+    r
+}
+
+/// hash_to_field from RFC 9380 Section 5.2 with m = 1 and count = 2.
+pub fn hash_to_field(msg: ByteSeq, dst: ByteSeq) -> Result<(FieldElement, FieldElement), String> {
+    let uniform_bytes = expand_message_xmd_sha256(msg, dst, 2 * L)?;
+    Ok((
+        field_element_from_be_bytes(uniform_bytes.sub(0, L)),
+        field_element_from_be_bytes(uniform_bytes.sub(L, L)),
+    ))
+}
+
+/// hash_to_field from RFC 9380 Section 5.2 with m = 1 and count = 1.
+pub fn hash_to_field_single(msg: ByteSeq, dst: ByteSeq) -> Result<FieldElement, String> {
+    let uniform_bytes = expand_message_xmd_sha256(msg, dst, L)?;
+    Ok(field_element_from_be_bytes(uniform_bytes))
+}
+
+/// The simplified SWU map to P-256 from RFC 9380 Section 6.6.2 with Z = -10.
+pub fn map_to_curve_simple_swu(u: FieldElement) -> Affine {
+    let one = FieldElement::from_literal(1);
+    let a = curve_a();
+    let b = p256::curve_b();
+    let z = sswu_z();
+    // x1 = (-B / A) * (1 + 1 / (Z^2 * u^4 + Z * u^2)), or B / (Z * A) if
+    // the denominator is 0
+    let tv1 = inv0(z.pow(2) * u.pow(4) + z * u.pow(2));
+    let x1 = if tv1 == FieldElement::from_literal(0) {
+        b * (z * a).inv()
+    } else {
+        (FieldElement::from_literal(0) - b) * a.inv() * (one + tv1)
+    };
+    let gx1 = x1.pow(3) + a * x1 + b;
+    let x2 = z * u.pow(2) * x1;
+    let gx2 = x2.pow(3) + a * x2 + b;
+    let (x, y) = if is_square(gx1) {
+        (x1, sqrt(gx1))
+    } else {
+        (x2, sqrt(gx2))
+    };
+    if sgn0(u) != sgn0(y) {
+        (x, FieldElement::from_literal(0) - y)
+    } else {
+        (x, y)
+    }
+}
+
+/// P256_XMD:SHA-256_SSWU_RO_
+/// Fails if the result is the point at infinity.
+pub fn p256_hash_to_curve(msg: ByteSeq, dst: ByteSeq) -> Result<Affine, String> {
+    let (u0, u1) = hash_to_field(msg, dst)?;
+    let q0 = p256::affine_to_projective(map_to_curve_simple_swu(u0));
+    let q1 = p256::affine_to_projective(map_to_curve_simple_swu(u1));
+    let r = p256::point_add(q0, q1);
+    if p256::is_point_at_infinity(r) {
+        return Err("Result is the point at infinity".to_string());
+    }
+    Ok(p256::projective_to_affine(r))
+}
+
+/// P256_XMD:SHA-256_SSWU_NU_
+/// The output is not uniformly distributed, see RFC 9380 Section 3.
+pub fn p256_encode_to_curve(msg: ByteSeq, dst: ByteSeq) -> Result<Affine, String> {
+    let u = hash_to_field_single(msg, dst)?;
+    Ok(map_to_curve_simple_swu(u))
+}
@@ -8,31 +8,60 @@ bytes!(PRK, HASH_LEN);
 
 // HMAC
 const BLOCK_LEN: usize = sha2::K_SIZE;
-bytes!(Block, BLOCK_LEN);
 
 // H(K XOR opad, H(K XOR ipad, text))
 pub fn hmac(k: ByteSeq, txt: ByteSeq) -> PRK {
-    let i_pad: Block = Block::from([0x36, 0x36, 0x36, 0x36, 0x36, 0x36, 0x36, 0x36,0x36, 0x36, 0x36, 0x36, 0x36, 0x36, 0x36, 0x36,0x36, 0x36, 0x36, 0x36, 0x36, 0x36, 0x36, 0x36,0x36, 0x36, 0x36, 0x36, 0x36, 0x36, 0x36, 0x36,0x36, 0x36, 0x36, 0x36, 0x36, 0x36, 0x36, 0x36,0x36, 0x36, 0x36, 0x36, 0x36, 0x36, 0x36, 0x36,0x36, 0x36, 0x36, 0x36, 0x36, 0x36, 0x36, 0x36, 0x36, 0x36, 0x36, 0x36, 0x36, 0x36, 0x36, 0x36]);
-    let o_pad: Block = Block::from([0x5c, 0x5c, 0x5c, 0x5c, 0x5c, 0x5c, 0x5c, 0x5c,0x5c, 0x5c, 0x5c, 0x5c, 0x5c, 0x5c, 0x5c, 0x5c,0x5c, 0x5c, 0x5c, 0x5c, 0x5c, 0x5c, 0x5c, 0x5c, 0x5c, 0x5c, 0x5c,0x5c, 0x5c, 0x5c, 0x5c, 0x5c, 0x5c, 0x5c, 0x5c,0x5c, 0x5c, 0x5c, 0x5c, 0x5c, 0x5c, 0x5c, 0x5c, 0x5c, 0x5c, 0x5c,0x5c, 0x5c, 0x5c, 0x5c, 0x5c, 0x5c, 0x5c, 0x5c,0x5c, 0x5c, 0x5c, 0x5c, 0x5c, 0x5c, 0x5c, 0x5c, 0x5c, 0x5c]);
-    
-    // Applications that use keys longer than B bytes will first hash the key using H and then use the resultant L byte string as the actual key to HMAC
-    let k_block = if k.len() > BLOCK_LEN {
-        sha2::hash(k).raw().into()
-    } else {
-        Block::copy_pad(k)
-    };
+    hmac_with(sha256, HASH_LEN, BLOCK_LEN, k, txt).raw().into()
+}
 
-    let k_ipad = k_block ^ i_pad;
-    let k_opad = k_block ^ o_pad;
+// HMAC with a hash function that has an output of `hash_len` and a block
+// size of `block_len` bytes.
+fn hmac_with(
+    hash: fn(ByteSeq) -> ByteSeq,
+    hash_len: usize,
+    block_len: usize,
+    k: ByteSeq,
+    txt: ByteSeq,
+) -> ByteSeq {
+    // Applications that use keys longer than B bytes will first hash the key using H and then use the resultant L byte string as the actual key to HMAC
+    let k = if k.len() > block_len { hash(k) } else { k };
+    let mut k_ipad = ByteSeq::new(block_len);
+    let mut k_opad = ByteSeq::new(block_len);
+    for i in 0..block_len {
+        let k_i = if i < k.len() { k[i] } else { U8(0) };
+        k_ipad[i] = k_i ^ U8(0x36);
+        k_opad[i] = k_i ^ U8(0x5c);
+    }
 
-    // TODO: we need something like append in the lib. Or do we want to stick with pre-allocation?
-    let mut h_in = ByteSeq::new(BLOCK_LEN + txt.len());
+    let mut h_in = ByteSeq::new(block_len + txt.len());
     h_in = h_in.update(0, k_ipad);
-    h_in = h_in.update(BLOCK_LEN, txt.clone());
-    let h_inner = sha2::hash(h_in);
+    h_in = h_in.update(block_len, txt);
+    let h_inner = hash(h_in);
 
-    let mut h_in = ByteSeq::new(BLOCK_LEN + h_inner.len());
+    let mut h_in = ByteSeq::new(block_len + hash_len);
     h_in = h_in.update(0, k_opad);
-    h_in = h_in.update(BLOCK_LEN, h_inner);
-    sha2::hash(h_in).raw().into()
+    h_in = h_in.update(block_len, h_inner);
+    hash(h_in)
+}
+
+fn sha256(msg: ByteSeq) -> ByteSeq {
+    ByteSeq::from(sha2::hash(msg).raw())
+}
+
+fn sha384(msg: ByteSeq) -> ByteSeq {
+    ByteSeq::from(sha2::sha384(msg).raw())
+}
+
+fn sha512(msg: ByteSeq) -> ByteSeq {
+    ByteSeq::from(sha2::sha512(msg).raw())
+}
+
+/// HMAC-SHA384 with a 48 byte output.
+pub fn hmac_sha384(k: ByteSeq, txt: ByteSeq) -> ByteSeq {
+    hmac_with(sha384, 48, 128, k, txt)
+}
+
+/// HMAC-SHA512 with a 64 byte output.
+pub fn hmac_sha512(k: ByteSeq, txt: ByteSeq) -> ByteSeq {
+    hmac_with(sha512, 64, 128, k, txt)
 }
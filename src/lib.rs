@@ -6,15 +6,33 @@
 
 pub mod aes;
 pub mod aesgcm;
+pub mod bip340;
 pub mod blake2b;
+pub mod blake2s;
 pub mod chacha20;
 pub mod chacha20poly1305;
 pub mod curve25519;
+pub mod curve448;
+pub mod ecdsa;
+pub mod ed25519;
+pub mod ed448;
+pub mod edwards448;
 pub mod gf128;
+pub mod hash_to_curve;
+pub mod hash_to_curve25519;
+pub mod hash_to_curve_p256;
 pub mod hpke;
+pub mod nacl;
 pub mod p256;
+pub mod p384;
+pub mod p521;
 pub mod poly1305;
+pub mod ristretto;
+pub mod salsa20;
+pub mod secp256k1;
 pub mod sha2;
+mod weierstrass;
 pub mod hkdf;
 pub mod hmac;
 pub mod fips202;
+pub mod xchacha20poly1305;
@@ -0,0 +1,81 @@
+// Import hacspec and all needed definitions.
+use hacspec::prelude::*;
+
+// Import primitives
+use crate::chacha20;
+use crate::curve25519::{scalarmult, SerializedPoint, SerializedScalar};
+use crate::poly1305::{poly, Poly1305, Tag};
+use crate::salsa20::{hsalsa, xsalsa20, HSalsaIV, Key, XIV};
+
+const TAG_LEN: usize = 16;
+const POLY_KEY_LEN: usize = 32;
+
+// The boxes use the libsodium "easy" format, i.e. the tag followed by the
+// cipher text, without the zero padding of the original NaCl API.
+
+/// crypto_secretbox: XSalsa20 encryption with a Poly1305 tag.
+/// The Poly1305 key is the first 32 bytes of the XSalsa20 key stream.
+pub fn secretbox(key: Key, iv: XIV, msg: ByteSeq) -> ByteSeq {
+    let mut padded_msg = ByteSeq::new(POLY_KEY_LEN + msg.len());
+    padded_msg = padded_msg.update(POLY_KEY_LEN, msg.clone());
+    let stream = xsalsa20(key, iv, padded_msg);
+    let mac_key = chacha20::Key::from_sub(stream.clone(), 0..POLY_KEY_LEN);
+    let cipher_text = stream.sub(POLY_KEY_LEN, msg.len());
+    let tag = poly(cipher_text.clone(), mac_key);
+
+    let mut out = ByteSeq::new(TAG_LEN + msg.len());
+    out = out.update(0, tag);
+    out = out.update(TAG_LEN, cipher_text);
+    out
+}
+
+pub fn secretbox_open(key: Key, iv: XIV, boxed: ByteSeq) -> Result<ByteSeq, String> {
+    if boxed.len() < TAG_LEN {
+        return Err("Box is too short".to_string());
+    }
+    let tag = Tag::from_sub(boxed.clone(), 0..TAG_LEN);
+    let cipher_text = boxed.sub(TAG_LEN, boxed.len() - TAG_LEN);
+    let mac_key = chacha20::Key::from_sub(
+        xsalsa20(key, iv, ByteSeq::new(POLY_KEY_LEN)),
+        0..POLY_KEY_LEN,
+    );
+    if Poly1305::new(mac_key)
+        .update(cipher_text.clone())
+        .verify(tag)
+    {
+        let mut padded_cipher_text = ByteSeq::new(POLY_KEY_LEN + cipher_text.len());
+        padded_cipher_text = padded_cipher_text.update(POLY_KEY_LEN, cipher_text.clone());
+        let msg = xsalsa20(key, iv, padded_cipher_text);
+        Ok(msg.sub(POLY_KEY_LEN, cipher_text.len()))
+    } else {
+        Err("Mac verification failed".to_string())
+    }
+}
+
+/// crypto_box_beforenm: HSalsa20 of the X25519 shared secret.
+pub fn box_beforenm(pk: SerializedPoint, sk: SerializedScalar) -> Key {
+    let shared = scalarmult(sk, pk);
+    hsalsa(Key::from_sub(shared, 0..32), HSalsaIV::new())
+}
+
+pub fn box_afternm(k: Key, iv: XIV, msg: ByteSeq) -> ByteSeq {
+    secretbox(k, iv, msg)
+}
+
+pub fn box_open_afternm(k: Key, iv: XIV, boxed: ByteSeq) -> Result<ByteSeq, String> {
+    secretbox_open(k, iv, boxed)
+}
+
+/// crypto_box: encrypt `msg` from the holder of `sk` to the holder of `pk`.
+pub fn crypto_box(pk: SerializedPoint, sk: SerializedScalar, iv: XIV, msg: ByteSeq) -> ByteSeq {
+    box_afternm(box_beforenm(pk, sk), iv, msg)
+}
+
+pub fn box_open(
+    pk: SerializedPoint,
+    sk: SerializedScalar,
+    iv: XIV,
+    boxed: ByteSeq,
+) -> Result<ByteSeq, String> {
+    box_open_afternm(box_beforenm(pk, sk), iv, boxed)
+}
@@ -0,0 +1,37 @@
+// Import hacspec and all needed definitions.
+use hacspec::prelude::*;
+
+use crate::weierstrass::weierstrass_curve;
+
+unsigned_integer!(FieldCanvas, 384);
+
+// Define field P384 (prime: 2**384 - 2**128 - 2**96 + 2**32 - 1)
+field_integer!(
+    FieldElement,
+    FieldCanvas,
+    FieldCanvas::from_hex("fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffeffffffff0000000000000000ffffffff")
+);
+
+unsigned_integer!(Scalar, 384);
+
+// Define the scalar field mod the group order
+field_integer!(
+    P384Scalar,
+    Scalar,
+    Scalar::from_hex("ffffffffffffffffffffffffffffffffffffffffffffffffc7634d81f4372ddf581a0db248b0a77aecec196accc52973")
+);
+
+// The curve P-384 from FIPS 186-4 Appendix D.1.2.4
+weierstrass_curve!(
+    a: -3,
+    field: FieldElement,
+    field_canvas: FieldCanvas,
+    scalar: Scalar,
+    field_bits: 384,
+    scalar_bits: 384,
+    coordinate_bytes: 48,
+    b: "b3312fa7e23ee7e4988e056be3f82d19181d9c6efe8141120314088f5013875ac656398d8a2ed19d2a85c8edd3ec2aef",
+    gx: "aa87ca22be8b05378eb1c71ef320ad746e1d3b628ba79b9859f741e082542a385502f25dbf55296c3a545e3872760ab7",
+    gy: "3617de4a96262c6f5d9e98bf9292dc29f8f41dbd289a147ce9da3113b5f0b8c00a60b1ce1d7e819d7a431d7c90ea0e5f",
+    sqrt_exponent: "3fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffbfffffffc00000000000000040000000",
+);
@@ -0,0 +1,37 @@
+// Import hacspec and all needed definitions.
+use hacspec::prelude::*;
+
+use crate::weierstrass::weierstrass_curve;
+
+unsigned_integer!(FieldCanvas, 521);
+
+// Define field P521 (prime: 2**521 - 1)
+field_integer!(
+    FieldElement,
+    FieldCanvas,
+    FieldCanvas::from_hex("01ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff")
+);
+
+unsigned_integer!(Scalar, 521);
+
+// Define the scalar field mod the group order
+field_integer!(
+    P521Scalar,
+    Scalar,
+    Scalar::from_hex("01fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffa51868783bf2f966b7fcc0148f709a5d03bb5c9b8899c47aebb6fb71e91386409")
+);
+
+// The curve P-521 from FIPS 186-4 Appendix D.1.2.5
+weierstrass_curve!(
+    a: -3,
+    field: FieldElement,
+    field_canvas: FieldCanvas,
+    scalar: Scalar,
+    field_bits: 521,
+    scalar_bits: 521,
+    coordinate_bytes: 66,
+    b: "0051953eb9618e1c9a1f929a21a0b68540eea2da725b99b315f3b8b489918ef109e156193951ec7e937b1652c0bd3bb1bf073573df883d2c34f1ef451fd46b503f00",
+    gx: "00c6858e06b70404e9cd9e3ecb662395b4429c648139053fb521f828af606b4d3dbaa14b5e77efe75928fe1dc127a2ffa8de3348b3c1856a429bf97e7e31c2e5bd66",
+    gy: "011839296a789a3bc0045c8a5fb42c7d1bd998f54449579b446817afbd17273e662c97ee72995ef42640c550b9013fad0761353c7086a272c24088be94769fd16650",
+    sqrt_exponent: "008000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000",
+);
@@ -1,7 +1,8 @@
 // Import hacspec and all needed definitions.
 use hacspec::prelude::*;
 
-// Import chacha20
+// Import chacha20 and aes
+use crate::aes;
 use crate::chacha20;
 use crate::chacha20::*;
 
@@ -44,8 +45,8 @@ fn encode(block: ByteSeq) -> FieldElement {
     w_elem + l_elem
 }
 
-fn poly_inner(m: ByteSeq, r: FieldElement) -> FieldElement {
-    let mut acc = FieldElement::from_literal(0);
+fn poly_inner(acc: FieldElement, m: ByteSeq, r: FieldElement) -> FieldElement {
+    let mut acc = acc;
     let m_len = m.len();
     for i in (0..m_len).step_by(BLOCKSIZE) {
         let block_len = min(BLOCKSIZE, m_len - i);
@@ -56,14 +57,7 @@ fn poly_inner(m: ByteSeq, r: FieldElement) -> FieldElement {
     acc
 }
 
-pub fn poly(m: ByteSeq, key: Key) -> Tag {
-    let s_elem = FieldElement::from_secret_literal(u128_from_le_bytes(U128Word::from_sub(
-        key,
-        BLOCKSIZE..2 * BLOCKSIZE,
-    )));
-    let r_elem = encode_r(Block::from_sub(key, 0..BLOCKSIZE));
-    let a = poly_inner(m, r_elem);
-    let n = a + s_elem;
+fn encode_tag(n: FieldElement) -> Tag {
     // Note that n might be less than 16 byte -> zero-pad; but might also be
     // larger than Tag::capacity().
     let n_v = n.to_byte_seq_le();
@@ -74,7 +68,78 @@ pub fn poly(m: ByteSeq, key: Key) -> Tag {
     tag
 }
 
+/// Incremental Poly1305.
+/// Bytes that don't fill a block are buffered until the next `update` or
+/// `finalize`.
+#[derive(Clone)]
+pub struct Poly1305 {
+    r: FieldElement,
+    s: FieldElement,
+    acc: FieldElement,
+    buffer: ByteSeq,
+}
+
+impl Poly1305 {
+    pub fn new(key: Key) -> Self {
+        let s_elem = FieldElement::from_secret_literal(u128_from_le_bytes(U128Word::from_sub(
+            key,
+            BLOCKSIZE..2 * BLOCKSIZE,
+        )));
+        let r_elem = encode_r(Block::from_sub(key, 0..BLOCKSIZE));
+        Self {
+            r: r_elem,
+            s: s_elem,
+            acc: FieldElement::from_literal(0),
+            buffer: ByteSeq::new(0),
+        }
+    }
+
+    pub fn update(self, m: ByteSeq) -> Self {
+        let buffer_len = self.buffer.len();
+        let mut data = ByteSeq::new(buffer_len + m.len());
+        data = data.update(0, self.buffer);
+        data = data.update(buffer_len, m);
+        let full_len = (data.len() / BLOCKSIZE) * BLOCKSIZE;
+        let acc = poly_inner(self.acc, data.sub(0, full_len), self.r);
+        Self {
+            r: self.r,
+            s: self.s,
+            acc,
+            buffer: data.sub(full_len, data.len() - full_len),
+        }
+    }
+
+    pub fn finalize(self) -> Tag {
+        let a = poly_inner(self.acc, self.buffer, self.r);
+        encode_tag(a + self.s)
+    }
+
+    /// Compare the tag with `tag` in constant time.
+    pub fn verify(self, tag: Tag) -> bool {
+        let my_tag = self.finalize();
+        let mut diff = U8(0);
+        for i in 0..BLOCKSIZE {
+            diff |= my_tag[i] ^ tag[i];
+        }
+        diff.declassify() == 0
+    }
+}
+
+pub fn poly(m: ByteSeq, key: Key) -> Tag {
+    Poly1305::new(key).update(m).finalize()
+}
+
 pub fn poly_mac(m: ByteSeq, key: Key, iv: IV) -> Tag {
     let mac_key = key_gen(key, iv);
     poly(m, mac_key)
 }
+
+/// Poly1305-AES as originally defined by Bernstein.
+/// The one-time key is `r` and `AES_k(nonce)`.
+pub fn poly_aes(m: ByteSeq, k: aes::Key, r: Block, nonce: aes::Block) -> Tag {
+    let s = aes::aes128_encrypt_block(k, nonce);
+    let mut key = Key::new();
+    key = key.update(0, r);
+    key = key.update(BLOCKSIZE, s);
+    poly(m, key)
+}
@@ -0,0 +1,175 @@
+// Import hacspec and all needed definitions.
+use hacspec::prelude::*;
+
+// Import primitives
+use crate::curve25519::{FieldCanvas, FieldElement};
+use crate::ed25519::{self, curve_d, fe_neg, fe_pow, sqrt_m1, Point, Scalar};
+
+// ristretto255 from RFC 9496.
+// Group elements are represented by Edwards25519 points in extended
+// coordinates. Different points may represent the same group element, so
+// elements must only be compared with `equals`.
+pub type RistrettoPoint = Point;
+
+bytes!(RistrettoPointEncoded, 32);
+bytes!(UniformBytes, 64);
+
+// Constants from RFC 9496 Section 4.1.
+fn sqrt_ad_minus_one() -> FieldElement {
+    FieldElement::from_hex("376931bf2b8348ac0f3cfcc931f5d1fdaf9d8e0c1b7854bd7e97f6a0497b2e1b")
+}
+
+fn invsqrt_a_minus_d() -> FieldElement {
+    FieldElement::from_hex("786c8905cfaffca216c27b91fe01d8409d2f16175a4172be99c8fdaa805d40ea")
+}
+
+fn one_minus_d_sq() -> FieldElement {
+    FieldElement::from_hex("029072a8b2b3e0d79994abddbe70dfe42c81a138cd5e350fe27c09c1945fc176")
+}
+
+fn d_minus_one_sq() -> FieldElement {
+    FieldElement::from_hex("5968b37af66c22414cdcd32f529b4eebd29e4a2cb01e199931ad5aaa44ed4d20")
+}
+
+// A field element is negative if its canonical encoding is odd.
+fn is_negative(x: FieldElement) -> bool {
+    x.bit(0)
+}
+
+fn abs(x: FieldElement) -> FieldElement {
+    if is_negative(x) {
+        fe_neg(x)
+    } else {
+        x
+    }
+}
+
+/// SQRT_RATIO_M1 from RFC 9496 Section 4.2.
+/// Returns whether u/v is square and the non-negative square root of u/v
+/// or of sqrt(-1) * u/v otherwise.
+pub fn sqrt_ratio_m1(u: FieldElement, v: FieldElement) -> (bool, FieldElement) {
+    let p58 =
+        FieldCanvas::from_hex("0ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffd");
+    let mut r = u * v.pow(3) * fe_pow(u * v.pow(7), p58);
+    let check = v * r.pow(2);
+    let correct_sign_sqrt = check == u;
+    let flipped_sign_sqrt = check == fe_neg(u);
+    let flipped_sign_sqrt_i = check == fe_neg(u) * sqrt_m1();
+    if flipped_sign_sqrt || flipped_sign_sqrt_i {
+        r = r * sqrt_m1();
+    }
+    (correct_sign_sqrt || flipped_sign_sqrt, abs(r))
+}
+
+/// Decode a group element as in RFC 9496 Section 4.3.1.
+pub fn decode(bytes: RistrettoPointEncoded) -> Result<RistrettoPoint, String> {
+    if (bytes[31] >> 7).declassify() != 0 {
+        return Err("Ristretto encoding is not canonical".to_string());
+    }
+    let s = FieldElement::from_byte_seq_le(bytes);
+    if RistrettoPointEncoded::copy_pad(s.to_byte_seq_le()) != bytes || is_negative(s) {
+        return Err("Ristretto encoding is not canonical".to_string());
+    }
+
+    let one = FieldElement::from_literal(1);
+    let ss = s.pow(2);
+    let u1 = one - ss;
+    let u2 = one + ss;
+    let u2_sqr = u2.pow(2);
+    let v = fe_neg(curve_d() * u1.pow(2)) - u2_sqr;
+    let (was_square, invsqrt) = sqrt_ratio_m1(one, v * u2_sqr);
+    let den_x = invsqrt * u2;
+    let den_y = invsqrt * den_x * v;
+    let x = abs(FieldElement::from_literal(2) * s * den_x);
+    let y = u1 * den_y;
+    let t = x * y;
+    if !was_square || is_negative(t) || y == FieldElement::from_literal(0) {
+        return Err("Invalid ristretto encoding".to_string());
+    }
+    Ok((x, y, one, t))
+}
+
+/// Encode a group element as in RFC 9496 Section 4.3.2.
+pub fn encode(p: RistrettoPoint) -> RistrettoPointEncoded {
+    let (x0, y0, z0, t0) = p;
+    let u1 = (z0 + y0) * (z0 - y0);
+    let u2 = x0 * y0;
+    let (_, invsqrt) = sqrt_ratio_m1(FieldElement::from_literal(1), u1 * u2.pow(2));
+    let den1 = invsqrt * u1;
+    let den2 = invsqrt * u2;
+    let z_inv = den1 * den2 * t0;
+    let (x, mut y, den_inv) = if is_negative(t0 * z_inv) {
+        (y0 * sqrt_m1(), x0 * sqrt_m1(), den1 * invsqrt_a_minus_d())
+    } else {
+        (x0, y0, den2)
+    };
+    if is_negative(x * z_inv) {
+        y = fe_neg(y);
+    }
+    let s = abs(den_inv * (z0 - y));
+    RistrettoPointEncoded::copy_pad(s.to_byte_seq_le())
+}
+
+/// Compare two group elements as in RFC 9496 Section 4.3.3.
+pub fn equals(p: RistrettoPoint, q: RistrettoPoint) -> bool {
+    let (x1, y1, _, _) = p;
+    let (x2, y2, _, _) = q;
+    x1 * y2 == y1 * x2 || y1 * y2 == x1 * x2
+}
+
+// MAP from RFC 9496 Section 4.3.4.
+fn map(t: FieldElement) -> RistrettoPoint {
+    let one = FieldElement::from_literal(1);
+    let minus_one = fe_neg(one);
+    let r = sqrt_m1() * t.pow(2);
+    let u = (r + one) * one_minus_d_sq();
+    let v = (minus_one - r * curve_d()) * (r + curve_d());
+    let (was_square, s) = sqrt_ratio_m1(u, v);
+    let (s, c) = if was_square {
+        (s, minus_one)
+    } else {
+        (fe_neg(abs(s * t)), r)
+    };
+    let n = c * (r - one) * d_minus_one_sq() - v;
+    let w0 = FieldElement::from_literal(2) * s * v;
+    let w1 = n * sqrt_ad_minus_one();
+    let w2 = one - s.pow(2);
+    let w3 = one + s.pow(2);
+    (w0 * w3, w2 * w1, w1 * w3, w0 * w2)
+}
+
+// The least significant 255 bits of 32 bytes as a field element.
+fn field_element_from_bytes(b: ByteSeq) -> FieldElement {
+    let mut masked = RistrettoPointEncoded::from(b);
+    masked[31] &= U8(0x7f);
+    FieldElement::from_byte_seq_le(masked)
+}
+
+/// The one-way map from 64 uniformly random bytes to a group element
+/// (RFC 9496 Section 4.3.4).
+pub fn from_uniform_bytes(b: UniformBytes) -> RistrettoPoint {
+    let b = ByteSeq::from(b.raw());
+    let p1 = map(field_element_from_bytes(b.sub(0, 32)));
+    let p2 = map(field_element_from_bytes(b.sub(32, 32)));
+    add(p1, p2)
+}
+
+pub fn identity() -> RistrettoPoint {
+    ed25519::point_identity()
+}
+
+pub fn generator() -> RistrettoPoint {
+    ed25519::base_point()
+}
+
+pub fn add(p: RistrettoPoint, q: RistrettoPoint) -> RistrettoPoint {
+    ed25519::point_add(p, q)
+}
+
+pub fn neg(p: RistrettoPoint) -> RistrettoPoint {
+    ed25519::point_neg(p)
+}
+
+pub fn mul(s: Scalar, p: RistrettoPoint) -> RistrettoPoint {
+    ed25519::point_mul(s, p)
+}
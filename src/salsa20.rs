@@ -0,0 +1,135 @@
+// Import hacspec and all needed definitions.
+use hacspec::prelude::*;
+
+// Salsa20 uses the same 16 word state as ChaCha20.
+use crate::chacha20::{state_to_bytes, State, StateBytes};
+
+bytes!(IV, 8);
+bytes!(HSalsaIV, 16);
+bytes!(XIV, 24);
+bytes!(Key, 32);
+
+fn line(a: u32, b: u32, c: u32, s: u32, m: State) -> State {
+    let mut state = m;
+    state[a] = state[a] ^ (state[b] + state[c]).rotate_left(s);
+    state
+}
+
+pub fn quarter_round(a: u32, b: u32, c: u32, d: u32, state: State) -> State {
+    let state = line(b, a, d, 7, state);
+    let state = line(c, b, a, 9, state);
+    let state = line(d, c, b, 13, state);
+    line(a, d, c, 18, state)
+}
+
+fn double_round(state: State) -> State {
+    // Column round
+    let state = quarter_round(0, 4, 8, 12, state);
+    let state = quarter_round(5, 9, 13, 1, state);
+    let state = quarter_round(10, 14, 2, 6, state);
+    let state = quarter_round(15, 3, 7, 11, state);
+
+    // Row round
+    let state = quarter_round(0, 1, 2, 3, state);
+    let state = quarter_round(5, 6, 7, 4, state);
+    let state = quarter_round(10, 11, 8, 9, state);
+    quarter_round(15, 12, 13, 14, state)
+}
+
+fn rounds(state: State, n_rounds: usize) -> State {
+    let mut state = state;
+    for _ in 0..n_rounds / 2 {
+        state = double_round(state);
+    }
+    state
+}
+
+// The Salsa20 core with `n_rounds` rounds, including the final addition.
+// Only used with 20 rounds for Salsa20 and 8 rounds for Salsa20/8.
+fn salsa_core(st: State, n_rounds: usize) -> State {
+    let mut state = rounds(st, n_rounds);
+    for i in 0..16 {
+        state[i] = state[i] + st[i];
+    }
+    state
+}
+
+/// Salsa20/8 on a 64 byte block as used in the scrypt BlockMix.
+pub fn salsa20_8(input: StateBytes) -> StateBytes {
+    let mut st = State::new();
+    for i in 0..16 {
+        st[i] = u32_from_le_bytes(U32Word::from_sub(input, 4 * i..4 * i + 4));
+    }
+    state_to_bytes(salsa_core(st, 8))
+}
+
+// The 16 byte input goes into words 6 to 9.
+// This is the 8 byte nonce and the 8 byte block counter for Salsa20 and
+// the 16 byte nonce for HSalsa20.
+fn init(key: Key, input: HSalsaIV) -> State {
+    State([
+        U32(0x6170_7865),
+        u32_from_le_bytes(U32Word::from_sub(key, 0..4)),
+        u32_from_le_bytes(U32Word::from_sub(key, 4..8)),
+        u32_from_le_bytes(U32Word::from_sub(key, 8..12)),
+        u32_from_le_bytes(U32Word::from_sub(key, 12..16)),
+        U32(0x3320_646e),
+        u32_from_le_bytes(U32Word::from_sub(input, 0..4)),
+        u32_from_le_bytes(U32Word::from_sub(input, 4..8)),
+        u32_from_le_bytes(U32Word::from_sub(input, 8..12)),
+        u32_from_le_bytes(U32Word::from_sub(input, 12..16)),
+        U32(0x7962_2d32),
+        u32_from_le_bytes(U32Word::from_sub(key, 16..20)),
+        u32_from_le_bytes(U32Word::from_sub(key, 20..24)),
+        u32_from_le_bytes(U32Word::from_sub(key, 24..28)),
+        u32_from_le_bytes(U32Word::from_sub(key, 28..32)),
+        U32(0x6b20_6574),
+    ])
+}
+
+pub fn block_init(key: Key, ctr: U64, iv: IV) -> State {
+    let mut input = HSalsaIV::new();
+    input = input.update(0, iv);
+    input = input.update(8, u64_to_le_bytes(ctr));
+    init(key, input)
+}
+
+fn block(key: Key, ctr: U64, iv: IV, n_rounds: usize) -> StateBytes {
+    let state = salsa_core(block_init(key, ctr, iv), n_rounds);
+    state_to_bytes(state)
+}
+
+fn salsa(key: Key, iv: IV, n_rounds: usize, m: ByteSeq) -> ByteSeq {
+    let mut ctr = U64(0);
+    let mut blocks_out = ByteSeq::new(m.len());
+    for (block_len, msg_block) in m.chunks(64) {
+        let key_block = block(key, ctr, iv, n_rounds);
+        blocks_out = blocks_out.push_sub(StateBytes::from(msg_block) ^ key_block, 0, block_len);
+        ctr += U64(1);
+    }
+    blocks_out
+}
+
+pub fn salsa20(key: Key, iv: IV, m: ByteSeq) -> ByteSeq {
+    salsa(key, iv, 20, m)
+}
+
+/// HSalsa20 derives a 256-bit subkey from a key and a 128-bit nonce.
+/// The output are the diagonal and the nonce words of the state after the
+/// rounds, without the final addition.
+pub fn hsalsa(key: Key, iv: HSalsaIV) -> Key {
+    let state = rounds(init(key, iv), 20);
+    let state_bytes = state_to_bytes(state);
+    let mut subkey = Key::new();
+    subkey = subkey.update_sub(0, state_bytes, 0, 4);
+    subkey = subkey.update_sub(4, state_bytes, 20, 4);
+    subkey = subkey.update_sub(8, state_bytes, 40, 4);
+    subkey = subkey.update_sub(12, state_bytes, 60, 4);
+    subkey = subkey.update_sub(16, state_bytes, 24, 16);
+    subkey
+}
+
+pub fn xsalsa20(key: Key, iv: XIV, m: ByteSeq) -> ByteSeq {
+    let subkey = hsalsa(key, HSalsaIV::from_sub(iv, 0..16));
+    salsa20(subkey, IV::from_sub(iv, 16..24), m)
+}
@@ -0,0 +1,37 @@
+// Import hacspec and all needed definitions.
+use hacspec::prelude::*;
+
+use crate::weierstrass::weierstrass_curve;
+
+unsigned_integer!(FieldCanvas, 256);
+
+// Define field secp256k1 (prime: 2**256 - 2**32 - 977)
+field_integer!(
+    FieldElement,
+    FieldCanvas,
+    FieldCanvas::from_hex("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f")
+);
+
+unsigned_integer!(Scalar, 256);
+
+// Define the scalar field mod the group order
+field_integer!(
+    Secp256k1Scalar,
+    Scalar,
+    Scalar::from_hex("fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141")
+);
+
+// The curve secp256k1 y^2 = x^3 + 7 from SEC 2 Section 2.4.1
+weierstrass_curve!(
+    a: 0,
+    field: FieldElement,
+    field_canvas: FieldCanvas,
+    scalar: Scalar,
+    field_bits: 256,
+    scalar_bits: 256,
+    coordinate_bytes: 32,
+    b: "0000000000000000000000000000000000000000000000000000000000000007",
+    gx: "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+    gy: "483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8",
+    sqrt_exponent: "3fffffffffffffffffffffffffffffffffffffffffffffffffffffffbfffff0c",
+);
@@ -114,8 +114,10 @@ fn compress(block: Block, h_in: Hash) -> Hash {
 pub fn hash(msg: ByteSeq) -> Digest {
     let mut h = Hash::from([0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a,
                               0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19]);
+    let mut padded = false;
     for (block_len, block) in msg.chunks(BLOCK_SIZE) {
         if block_len < BLOCK_SIZE {
+            padded = true;
             // Add padding for last block
             let mut last_block = Block::new();
             last_block = last_block.update(0, Block::from(block));
@@ -134,6 +136,191 @@ pub fn hash(msg: ByteSeq) -> Digest {
             h = compress(block.into(), h);
         }
     }
+    if !padded {
+        // The message fills all blocks (or is empty) and the padding needs
+        // a block of its own.
+        let mut pad_block = Block::new();
+        pad_block[0] = U8(0x80);
+        let len_bist: U64 = (msg.len() * 8).into();
+        pad_block = pad_block.update(BLOCK_SIZE - LEN_SIZE, u64_to_be_bytes(len_bist));
+        h = compress(pad_block, h);
+    }
 
     Digest::from(&h.to_bytes_be()[..])
 }
+
+// SHA-512
+
+const BLOCK_SIZE_512: usize = 128;
+const LEN_SIZE_512: usize = 16;
+pub const K_SIZE_512: usize = 80;
+pub const HASH_SIZE_512: usize = Variant::SHA512 as usize / 8;
+
+type Word64T = U64;
+bytes!(Block512, BLOCK_SIZE_512);
+bytes!(Digest512, HASH_SIZE_512);
+array!(RoundConstantsTable512, K_SIZE_512, U64);
+array!(Hash512, 8, U64);
+
+// Rotations and shifts for Sigma0, Sigma1, sigma0, sigma1.
+static OP_TABLE_512: [u32; 12] = [28, 34, 39, 14, 18, 41, 1, 8, 7, 19, 61, 6];
+
+static K_TABLE_512: [u64; K_SIZE_512] = [
+    0x428a_2f98_d728_ae22, 0x7137_4491_23ef_65cd, 0xb5c0_fbcf_ec4d_3b2f, 0xe9b5_dba5_8189_dbbc,
+    0x3956_c25b_f348_b538, 0x59f1_11f1_b605_d019, 0x923f_82a4_af19_4f9b, 0xab1c_5ed5_da6d_8118,
+    0xd807_aa98_a303_0242, 0x1283_5b01_4570_6fbe, 0x2431_85be_4ee4_b28c, 0x550c_7dc3_d5ff_b4e2,
+    0x72be_5d74_f27b_896f, 0x80de_b1fe_3b16_96b1, 0x9bdc_06a7_25c7_1235, 0xc19b_f174_cf69_2694,
+    0xe49b_69c1_9ef1_4ad2, 0xefbe_4786_384f_25e3, 0x0fc1_9dc6_8b8c_d5b5, 0x240c_a1cc_77ac_9c65,
+    0x2de9_2c6f_592b_0275, 0x4a74_84aa_6ea6_e483, 0x5cb0_a9dc_bd41_fbd4, 0x76f9_88da_8311_53b5,
+    0x983e_5152_ee66_dfab, 0xa831_c66d_2db4_3210, 0xb003_27c8_98fb_213f, 0xbf59_7fc7_beef_0ee4,
+    0xc6e0_0bf3_3da8_8fc2, 0xd5a7_9147_930a_a725, 0x06ca_6351_e003_826f, 0x1429_2967_0a0e_6e70,
+    0x27b7_0a85_46d2_2ffc, 0x2e1b_2138_5c26_c926, 0x4d2c_6dfc_5ac4_2aed, 0x5338_0d13_9d95_b3df,
+    0x650a_7354_8baf_63de, 0x766a_0abb_3c77_b2a8, 0x81c2_c92e_47ed_aee6, 0x9272_2c85_1482_353b,
+    0xa2bf_e8a1_4cf1_0364, 0xa81a_664b_bc42_3001, 0xc24b_8b70_d0f8_9791, 0xc76c_51a3_0654_be30,
+    0xd192_e819_d6ef_5218, 0xd699_0624_5565_a910, 0xf40e_3585_5771_202a, 0x106a_a070_32bb_d1b8,
+    0x19a4_c116_b8d2_d0c8, 0x1e37_6c08_5141_ab53, 0x2748_774c_df8e_eb99, 0x34b0_bcb5_e19b_48a8,
+    0x391c_0cb3_c5c9_5a63, 0x4ed8_aa4a_e341_8acb, 0x5b9c_ca4f_7763_e373, 0x682e_6ff3_d6b2_b8a3,
+    0x748f_82ee_5def_b2fc, 0x78a5_636f_4317_2f60, 0x84c8_7814_a1f0_ab72, 0x8cc7_0208_1a64_39ec,
+    0x90be_fffa_2363_1e28, 0xa450_6ceb_de82_bde9, 0xbef9_a3f7_b2c6_7915, 0xc671_78f2_e372_532b,
+    0xca27_3ece_ea26_619c, 0xd186_b8c7_21c0_c207, 0xeada_7dd6_cde0_eb1e, 0xf57d_4f7f_ee6e_d178,
+    0x06f0_67aa_7217_6fba, 0x0a63_7dc5_a2c8_98a6, 0x113f_9804_bef9_0dae, 0x1b71_0b35_131c_471b,
+    0x28db_77f5_2304_7d84, 0x32ca_ab7b_40c7_2493, 0x3c9e_be0a_15c9_bebc, 0x431d_67c4_9c10_0d4c,
+    0x4cc5_d4be_cb3e_42b6, 0x597f_299c_fc65_7e2a, 0x5fcb_6fab_3ad6_faec, 0x6c44_198c_4a47_5817,
+];
+
+static IV_512: [u64; 8] = [
+    0x6a09_e667_f3bc_c908, 0xbb67_ae85_84ca_a73b, 0x3c6e_f372_fe94_f82b, 0xa54f_f53a_5f1d_36f1,
+    0x510e_527f_ade6_82d1, 0x9b05_688c_2b3e_6c1f, 0x1f83_d9ab_fb41_bd6b, 0x5be0_cd19_137e_2179,
+];
+
+// SHA-384 is SHA-512 with a different IV, truncated to 48 bytes.
+pub const HASH_SIZE_384: usize = 48;
+bytes!(Digest384, HASH_SIZE_384);
+
+static IV_384: [u64; 8] = [
+    0xcbbb_9d5d_c105_9ed8, 0x629a_292a_367c_d507, 0x9159_015a_3070_dd17, 0x152f_ecd8_f70e_5939,
+    0x6733_2667_ffc0_0b31, 0x8eb4_4a87_6858_1511, 0xdb0c_2e0d_64f9_8fa7, 0x47b5_481d_befa_4fa4,
+];
+
+fn ch_64(x: Word64T, y: Word64T, z: Word64T) -> Word64T {
+    (x & y) ^ ((!x) & z)
+}
+
+fn maj_64(x: Word64T, y: Word64T, z: Word64T) -> Word64T {
+    (x & y) ^ ((x & z) ^ (y & z))
+}
+
+fn sigma_64(x: Word64T, i: usize, op: usize) -> Word64T {
+    let tmp: Word64T = if op == 0 {
+        x >> OP_TABLE_512[3 * i + 2]
+    } else {
+        x.rotate_right(OP_TABLE_512[3 * i + 2])
+    };
+    x.rotate_right(OP_TABLE_512[3 * i]) ^ x.rotate_right(OP_TABLE_512[3 * i + 1]) ^ tmp
+}
+
+fn schedule_64(block: Block512) -> RoundConstantsTable512 {
+    let mut s = RoundConstantsTable512::new();
+    for i in 0..K_SIZE_512 {
+        if i < 16 {
+            s[i] = u64_from_be_bytes(U64Word::from_sub(block, 8 * i..8 * i + 8));
+        } else {
+            let t16 = s[i - 16];
+            let t15 = s[i - 15];
+            let t7 = s[i - 7];
+            let t2 = s[i - 2];
+            let s1 = sigma_64(t2, 3, 0);
+            let s0 = sigma_64(t15, 2, 0);
+            s[i] = s1 + t7 + s0 + t16;
+        }
+    }
+    s
+}
+
+fn shuffle_64(ws: RoundConstantsTable512, hashi: Hash512) -> Hash512 {
+    let mut h = hashi;
+    for i in 0..K_SIZE_512 {
+        let a0 = h[0];
+        let b0 = h[1];
+        let c0 = h[2];
+        let d0 = h[3];
+        let e0 = h[4];
+        let f0 = h[5];
+        let g0 = h[6];
+        let h0: Word64T = h[7];
+
+        let t1 = h0 + sigma_64(e0, 1, 1) + ch_64(e0, f0, g0) + U64(K_TABLE_512[i]) + ws[i];
+        let t2 = sigma_64(a0, 0, 1) + maj_64(a0, b0, c0);
+
+        h[0] = t1 + t2;
+        h[1] = a0;
+        h[2] = b0;
+        h[3] = c0;
+        h[4] = d0 + t1;
+        h[5] = e0;
+        h[6] = f0;
+        h[7] = g0;
+    }
+    h
+}
+
+fn compress_64(block: Block512, h_in: Hash512) -> Hash512 {
+    let s = schedule_64(block);
+    let mut h = shuffle_64(s, h_in);
+    for i in 0..8 {
+        h[i] += h_in[i];
+    }
+    h
+}
+
+fn hash_64(msg: ByteSeq, h_init: Hash512) -> Hash512 {
+    let mut h = h_init;
+    let n_blocks = msg.len() / BLOCK_SIZE_512;
+    for i in 0..n_blocks {
+        let block = Block512::from_sub(msg.clone(), i * BLOCK_SIZE_512..(i + 1) * BLOCK_SIZE_512);
+        h = compress_64(block, h);
+    }
+
+    // Add padding for the last, possibly empty, block
+    let block_len = msg.len() - n_blocks * BLOCK_SIZE_512;
+    let mut last_block = Block512::new();
+    last_block = last_block.update_sub(0, msg.clone(), n_blocks * BLOCK_SIZE_512, block_len);
+    last_block[block_len] = U8(0x80);
+    let len_bits = u128_to_be_bytes(U128((msg.len() * 8) as u128));
+    if block_len < BLOCK_SIZE_512 - LEN_SIZE_512 {
+        last_block = last_block.update(BLOCK_SIZE_512 - LEN_SIZE_512, len_bits);
+        h = compress_64(last_block, h);
+    } else {
+        let mut pad_block = Block512::new();
+        pad_block = pad_block.update(BLOCK_SIZE_512 - LEN_SIZE_512, len_bits);
+        h = compress_64(last_block, h);
+        h = compress_64(pad_block, h);
+    }
+    h
+}
+
+pub fn sha512(msg: ByteSeq) -> Digest512 {
+    let mut h_init = Hash512::new();
+    for i in 0..8 {
+        h_init[i] = U64(IV_512[i]);
+    }
+    let h = hash_64(msg, h_init);
+    let mut d = Digest512::new();
+    for i in 0..8 {
+        d = d.update(8 * i, u64_to_be_bytes(h[i]));
+    }
+    d
+}
+
+pub fn sha384(msg: ByteSeq) -> Digest384 {
+    let mut h_init = Hash512::new();
+    for i in 0..8 {
+        h_init[i] = U64(IV_384[i]);
+    }
+    let h = hash_64(msg, h_init);
+    let mut d = Digest384::new();
+    for i in 0..6 {
+        d = d.update(8 * i, u64_to_be_bytes(h[i]));
+    }
+    d
+}
@@ -0,0 +1,433 @@
+// Short Weierstrass curves y^2 = x^3 + ax + b with a = -3 or a = 0 over
+// prime fields with p = 3 mod 4, i.e. the NIST curves P-256, P-384 and
+// P-521 and secp256k1.
+//
+// `weierstrass_curve!` defines the point arithmetic, ECDH and the SEC1
+// encodings of a curve in the module where it is invoked. The module has to
+// define the field with `field_integer!`, the unsigned integer the field is
+// built on, and an unsigned integer for scalars.
+//
+// The macro arguments are
+//   a: -3 or 0, which selects the addition and doubling formulas
+//   field, field_canvas, scalar: the field, its canvas and the scalar type
+//   field_bits: the bit length of p, i.e. of all field canvas values
+//   scalar_bits: the bit length of the scalar type
+//   coordinate_bytes: the length of an encoded field element
+//   b, gx, gy: the curve constant b and the generator as hex strings
+//   sqrt_exponent: (p + 1) / 4 as hex string
+macro_rules! weierstrass_curve {
+    (a: -3, field: $fe:ident, $($rest:tt)*) => {
+        $crate::weierstrass::weierstrass_curve!(@common field: $fe, $($rest)*);
+
+        fn curve_rhs(x: $fe) -> $fe {
+            x.pow(3) - $fe::from_literal(3) * x + curve_b()
+        }
+
+        /// Complete addition for a = -3 from Renes, Costello, Batina,
+        /// "Complete addition formulas for prime order elliptic curves",
+        /// Algorithm 4.
+        /// The formulas work for all inputs, including P + P, P + (-P) and
+        /// the point at infinity, without branching on the inputs.
+        pub fn point_add(p: Projective, q: Projective) -> Projective {
+            let (x1, y1, z1) = p;
+            let (x2, y2, z2) = q;
+            let b = curve_b();
+            let t0 = x1 * x2;
+            let t1 = y1 * y2;
+            let t2 = z1 * z2;
+            let t3 = (x1 + y1) * (x2 + y2);
+            let t4 = t0 + t1;
+            let t3 = t3 - t4;
+            let t4 = (y1 + z1) * (y2 + z2);
+            let x3 = t1 + t2;
+            let t4 = t4 - x3;
+            let x3 = (x1 + z1) * (x2 + z2);
+            let y3 = t0 + t2;
+            let y3 = x3 - y3;
+            let z3 = b * t2;
+            let x3 = y3 - z3;
+            let z3 = x3 + x3;
+            let x3 = x3 + z3;
+            let z3 = t1 - x3;
+            let x3 = t1 + x3;
+            let y3 = b * y3;
+            let t1 = t2 + t2;
+            let t2 = t1 + t2;
+            let y3 = y3 - t2;
+            let y3 = y3 - t0;
+            let t1 = y3 + y3;
+            let y3 = t1 + y3;
+            let t1 = t0 + t0;
+            let t0 = t1 + t0;
+            let t0 = t0 - t2;
+            let t1 = t4 * y3;
+            let t2 = t0 * y3;
+            let y3 = x3 * z3;
+            let y3 = y3 + t2;
+            let x3 = t3 * x3;
+            let x3 = x3 - t1;
+            let z3 = t4 * z3;
+            let t1 = t3 * t0;
+            let z3 = z3 + t1;
+            (x3, y3, z3)
+        }
+
+        /// Complete doubling for a = -3 (Algorithm 6 of the same paper).
+        pub fn point_double(p: Projective) -> Projective {
+            let (x, y, z) = p;
+            let b = curve_b();
+            let t0 = x * x;
+            let t1 = y * y;
+            let t2 = z * z;
+            let t3 = x * y;
+            let t3 = t3 + t3;
+            let z3 = x * z;
+            let z3 = z3 + z3;
+            let y3 = b * t2;
+            let y3 = y3 - z3;
+            let x3 = y3 + y3;
+            let y3 = x3 + y3;
+            let x3 = t1 - y3;
+            let y3 = t1 + y3;
+            let y3 = x3 * y3;
+            let x3 = x3 * t3;
+            let t3 = t2 + t2;
+            let t2 = t2 + t3;
+            let z3 = b * z3;
+            let z3 = z3 - t2;
+            let z3 = z3 - t0;
+            let t3 = z3 + z3;
+            let z3 = z3 + t3;
+            let t3 = t0 + t0;
+            let t0 = t3 + t0;
+            let t0 = t0 - t2;
+            let t0 = t0 * z3;
+            let y3 = y3 + t0;
+            let t0 = y * z;
+            let t0 = t0 + t0;
+            let z3 = t0 * z3;
+            let x3 = x3 - z3;
+            let z3 = t0 * t1;
+            let z3 = z3 + z3;
+            let z3 = z3 + z3;
+            (x3, y3, z3)
+        }
+    };
+    (a: 0, field: $fe:ident, $($rest:tt)*) => {
+        $crate::weierstrass::weierstrass_curve!(@common field: $fe, $($rest)*);
+
+        fn curve_rhs(x: $fe) -> $fe {
+            x.pow(3) + curve_b()
+        }
+
+        /// Complete addition for a = 0 from Renes, Costello, Batina,
+        /// "Complete addition formulas for prime order elliptic curves",
+        /// Algorithm 7.
+        /// The formulas work for all inputs, including P + P, P + (-P) and
+        /// the point at infinity, without branching on the inputs.
+        pub fn point_add(p: Projective, q: Projective) -> Projective {
+            let (x1, y1, z1) = p;
+            let (x2, y2, z2) = q;
+            let b3 = $fe::from_literal(3) * curve_b();
+            let t0 = x1 * x2;
+            let t1 = y1 * y2;
+            let t2 = z1 * z2;
+            let t3 = (x1 + y1) * (x2 + y2);
+            let t4 = t0 + t1;
+            let t3 = t3 - t4;
+            let t4 = (y1 + z1) * (y2 + z2);
+            let x3 = t1 + t2;
+            let t4 = t4 - x3;
+            let x3 = (x1 + z1) * (x2 + z2);
+            let y3 = t0 + t2;
+            let y3 = x3 - y3;
+            let x3 = t0 + t0;
+            let t0 = x3 + t0;
+            let t2 = b3 * t2;
+            let z3 = t1 + t2;
+            let t1 = t1 - t2;
+            let y3 = b3 * y3;
+            let x3 = t4 * y3;
+            let t2 = t3 * t1;
+            let x3 = t2 - x3;
+            let y3 = y3 * t0;
+            let t1 = t1 * z3;
+            let y3 = t1 + y3;
+            let t0 = t0 * t3;
+            let z3 = z3 * t4;
+            let z3 = z3 + t0;
+            (x3, y3, z3)
+        }
+
+        /// Complete doubling for a = 0 (Algorithm 9 of the same paper).
+        pub fn point_double(p: Projective) -> Projective {
+            let (x, y, z) = p;
+            let b3 = $fe::from_literal(3) * curve_b();
+            let t0 = y * y;
+            let z3 = t0 + t0;
+            let z3 = z3 + z3;
+            let z3 = z3 + z3;
+            let t1 = y * z;
+            let t2 = z * z;
+            let t2 = b3 * t2;
+            let x3 = t2 * z3;
+            let y3 = t0 + t2;
+            let z3 = t1 * z3;
+            let t1 = t2 + t2;
+            let t2 = t1 + t2;
+            let t0 = t0 - t2;
+            let y3 = t0 * y3;
+            let y3 = x3 + y3;
+            let t1 = x * y;
+            let x3 = t0 * t1;
+            let x3 = x3 + x3;
+            (x3, y3, z3)
+        }
+    };
+    (
+        @common
+        field: $fe:ident,
+        field_canvas: $fc:ident,
+        scalar: $sc:ident,
+        field_bits: $fbits:expr,
+        scalar_bits: $sbits:expr,
+        coordinate_bytes: $len:expr,
+        b: $b:expr,
+        gx: $gx:expr,
+        gy: $gy:expr,
+        sqrt_exponent: $sqrt:expr $(,)?
+    ) => {
+        const COORDINATE_BYTES: usize = $len;
+
+        // Homogeneous projective coordinates (X : Y : Z) with x = X/Z and
+        // y = Y/Z. The point at infinity is (0 : 1 : 0).
+        pub type Projective = ($fe, $fe, $fe);
+        pub type Affine = ($fe, $fe);
+
+        // SEC1 point encodings
+        bytes!(UncompressedPoint, 2 * COORDINATE_BYTES + 1);
+        bytes!(CompressedPoint, COORDINATE_BYTES + 1);
+        bytes!(SharedSecret, COORDINATE_BYTES);
+
+        /// b from y^2 = x^3 + ax + b
+        pub fn curve_b() -> $fe {
+            $fe::from_hex($b)
+        }
+
+        /// Convert a point that is not the point at infinity to affine
+        /// coordinates.
+        pub fn projective_to_affine(p: Projective) -> Affine {
+            let (x, y, z) = p;
+            let z_inv = z.inv();
+            (x * z_inv, y * z_inv)
+        }
+
+        pub fn affine_to_projective(p: Affine) -> Projective {
+            (p.0, p.1, $fe::from_literal(1))
+        }
+
+        pub fn point_identity() -> Projective {
+            (
+                $fe::from_literal(0),
+                $fe::from_literal(1),
+                $fe::from_literal(0),
+            )
+        }
+
+        pub fn is_point_at_infinity(p: Projective) -> bool {
+            p.2 == $fe::from_literal(0)
+        }
+
+        pub fn point_eq(p: Projective, q: Projective) -> bool {
+            let (x1, y1, z1) = p;
+            let (x2, y2, z2) = q;
+            x1 * z2 == x2 * z1 && y1 * z2 == y2 * z1
+        }
+
+        pub fn point_neg(p: Projective) -> Projective {
+            let (x, y, z) = p;
+            (x, $fe::from_literal(0) - y, z)
+        }
+
+        fn montgomery_ladder(k: $sc, init: Projective) -> Projective {
+            let mut p_working = (point_identity(), init);
+            for i in 0..$sbits {
+                if k.bit($sbits - 1 - i) {
+                    p_working = (p_working.1, p_working.0);
+                }
+                let xx = point_double(p_working.0);
+                let xp1 = point_add(p_working.0, p_working.1);
+                if k.bit($sbits - 1 - i) {
+                    p_working = (xp1, xx);
+                } else {
+                    p_working = (xx, xp1);
+                }
+            }
+            p_working.0
+        }
+
+        pub fn base_point() -> Projective {
+            ($fe::from_hex($gx), $fe::from_hex($gy), $fe::from_literal(1))
+        }
+
+        fn to_affine(p: Projective) -> Result<Affine, String> {
+            if is_point_at_infinity(p) {
+                return Err("Result is the point at infinity".to_string());
+            }
+            Ok(projective_to_affine(p))
+        }
+
+        /// k * G for the base point G.
+        /// Fails if the result is the point at infinity, i.e. if k is 0 mod
+        /// n.
+        pub fn point_mul_base(k: $sc) -> Result<Affine, String> {
+            to_affine(montgomery_ladder(k, base_point()))
+        }
+
+        /// k * P for an arbitrary point P.
+        /// Fails if the result is the point at infinity.
+        pub fn point_mul(k: $sc, p: Affine) -> Result<Affine, String> {
+            to_affine(montgomery_ladder(k, affine_to_projective(p)))
+        }
+
+        /// a * G + b * Q for the base point G with Shamir's trick, i.e. with
+        /// a single chain of doublings for both scalars.
+        /// This branches on the bits of the scalars and must only be used
+        /// with public scalars, e.g. in ECDSA verification.
+        /// Fails if the result is the point at infinity.
+        pub fn point_mul_add(a: $sc, b: $sc, q: Affine) -> Result<Affine, String> {
+            let g = base_point();
+            let q = affine_to_projective(q);
+            let g_q = point_add(g, q);
+            let mut r = point_identity();
+            for i in 0..$sbits {
+                r = point_double(r);
+                let a_i = a.bit($sbits - 1 - i);
+                let b_i = b.bit($sbits - 1 - i);
+                if a_i && b_i {
+                    r = point_add(r, g_q);
+                } else if a_i {
+                    r = point_add(r, g);
+                } else if b_i {
+                    r = point_add(r, q);
+                }
+            }
+            to_affine(r)
+        }
+
+        /// x^e for a public exponent `e`.
+        pub fn fe_pow(x: $fe, e: $fc) -> $fe {
+            let mut r = $fe::from_literal(1);
+            for i in 0..$fbits {
+                r = r * r;
+                if e.bit($fbits - 1 - i) {
+                    r = r * x;
+                }
+            }
+            r
+        }
+
+        pub fn is_point_on_curve(p: Affine) -> bool {
+            let (x, y) = p;
+            y.pow(2) == curve_rhs(x)
+        }
+
+        /// Diffie-Hellman with the peer's public point `p`.
+        /// Fails if `p` is not on the curve or the shared point is the point
+        /// at infinity, i.e. if `sk` is 0 mod the group order.
+        pub fn ecdh(sk: $sc, p: Affine) -> Result<Affine, String> {
+            if !is_point_on_curve(p) {
+                return Err("Point is not on the curve".to_string());
+            }
+            point_mul(sk, p)
+        }
+
+        /// The shared secret is the x-coordinate of the shared point (SEC1
+        /// 3.3.1).
+        pub fn ecdh_shared_secret(sk: $sc, p: Affine) -> Result<SharedSecret, String> {
+            let (x, _) = ecdh(sk, p)?;
+            Ok(SharedSecret::from(field_element_to_be_bytes(x)))
+        }
+
+        // Fixed-length big-endian encoding, including leading zeros
+        fn field_element_to_be_bytes(x: $fe) -> ByteSeq {
+            let b = x.to_byte_seq_be();
+            ByteSeq::new(COORDINATE_BYTES).update(COORDINATE_BYTES - b.len(), b)
+        }
+
+        fn field_element_from_be_bytes(b: ByteSeq) -> Result<$fe, String> {
+            let x = $fe::from_byte_seq_be(b.clone());
+            if field_element_to_be_bytes(x) != b {
+                return Err("Coordinate is not reduced".to_string());
+            }
+            Ok(x)
+        }
+
+        /// SEC1 uncompressed encoding 0x04 || x || y.
+        pub fn serialize_uncompressed(p: Affine) -> UncompressedPoint {
+            let (x, y) = p;
+            let mut out = UncompressedPoint::new();
+            out[0] = U8(0x04);
+            out = out.update(1, field_element_to_be_bytes(x));
+            out = out.update(1 + COORDINATE_BYTES, field_element_to_be_bytes(y));
+            out
+        }
+
+        /// SEC1 compressed encoding 0x02 || x for even y and 0x03 || x for
+        /// odd y.
+        pub fn serialize_compressed(p: Affine) -> CompressedPoint {
+            let (x, y) = p;
+            let mut out = CompressedPoint::new();
+            out[0] = if y.bit(0) { U8(0x03) } else { U8(0x02) };
+            out = out.update(1, field_element_to_be_bytes(x));
+            out
+        }
+
+        pub fn deserialize_uncompressed(s: UncompressedPoint) -> Result<Affine, String> {
+            if s[0].declassify() != 0x04 {
+                return Err("Invalid point encoding".to_string());
+            }
+            let b = ByteSeq::from(s.raw());
+            let x = field_element_from_be_bytes(b.sub(1, COORDINATE_BYTES))?;
+            let y = field_element_from_be_bytes(b.sub(1 + COORDINATE_BYTES, COORDINATE_BYTES))?;
+            if !is_point_on_curve((x, y)) {
+                return Err("Point is not on the curve".to_string());
+            }
+            Ok((x, y))
+        }
+
+        /// Decompress a point by computing y = sqrt(x^3 + ax + b).
+        /// Because p = 3 mod 4 the square root is
+        /// (x^3 + ax + b)^((p + 1) / 4).
+        pub fn deserialize_compressed(s: CompressedPoint) -> Result<Affine, String> {
+            let y_odd = match s[0].declassify() {
+                0x02 => false,
+                0x03 => true,
+                _ => return Err("Invalid point encoding".to_string()),
+            };
+            let x = field_element_from_be_bytes(ByteSeq::from(s.raw()).sub(1, COORDINATE_BYTES))?;
+            let rhs = curve_rhs(x);
+            let mut y = fe_pow(rhs, $fc::from_hex($sqrt));
+            if y.pow(2) != rhs {
+                return Err("Point is not on the curve".to_string());
+            }
+            if y.bit(0) != y_odd {
+                y = $fe::from_literal(0) - y;
+            }
+            Ok((x, y))
+        }
+
+        /// Decode a compressed or uncompressed SEC1 point.
+        pub fn deserialize(s: ByteSeq) -> Result<Affine, String> {
+            if s.len() == COORDINATE_BYTES + 1 {
+                deserialize_compressed(CompressedPoint::from(s))
+            } else if s.len() == 2 * COORDINATE_BYTES + 1 {
+                deserialize_uncompressed(UncompressedPoint::from(s))
+            } else {
+                Err("Invalid point encoding length".to_string())
+            }
+        }
+    };
+}
+
+pub(crate) use weierstrass_curve;
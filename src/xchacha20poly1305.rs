@@ -0,0 +1,34 @@
+// Import hacspec and all needed definitions.
+use hacspec::prelude::*;
+
+// Import chacha20, poly1305, and the IETF AEAD
+use crate::chacha20::*;
+use crate::chacha20poly1305;
+use crate::poly1305::*;
+
+bytes!(XIV, 24);
+
+// Derive the subkey with HChaCha20 from the first 16 bytes of the nonce.
+// The ChaCha20 nonce is four zero bytes followed by the last 8 bytes.
+fn subkey_iv(key: Key, iv: XIV) -> (Key, IV) {
+    let subkey = hchacha(key, HChaChaIV::from_sub(iv, 0..16));
+    let mut chacha_iv = IV::new();
+    chacha_iv = chacha_iv.update_sub(4, iv, 16, 8);
+    (subkey, chacha_iv)
+}
+
+pub fn encrypt(key: Key, iv: XIV, aad: ByteSeq, msg: ByteSeq) -> Result<(ByteSeq, Tag), String> {
+    let (subkey, chacha_iv) = subkey_iv(key, iv);
+    chacha20poly1305::encrypt(subkey, chacha_iv, aad, msg)
+}
+
+pub fn decrypt(
+    key: Key,
+    iv: XIV,
+    aad: ByteSeq,
+    cipher_text: ByteSeq,
+    tag: Tag,
+) -> Result<ByteSeq, String> {
+    let (subkey, chacha_iv) = subkey_iv(key, iv);
+    chacha20poly1305::decrypt(subkey, chacha_iv, aad, cipher_text, tag)
+}
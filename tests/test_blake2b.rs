@@ -65,3 +65,149 @@ fn test_multi_block_string_longer() {
         h.iter().map(|x| U8::declassify(*x)).collect::<Vec<_>>()
     );
 }
+
+fn seq(len: usize) -> ByteSeq {
+    let mut s = ByteSeq::new(len);
+    for i in 0..len {
+        s[i] = U8(i as u8);
+    }
+    s
+}
+
+// Keyed vectors from the BLAKE2 KAT file blake2b-kat.txt with
+// key = 00 01 .. 3f and in = 00 01 .. (len - 1)
+const KEYED_KAT: [(usize, &str); 7] = [
+    (
+        0,
+        "10ebb67700b1868efb4417987acf4690ae9d972fb7a590c2f02871799aaa4786b5e996e8f0f4eb981fc214b005f42d2ff4233499391653df7aefcbc13fc51568",
+    ),
+    (
+        1,
+        "961f6dd1e4dd30f63901690c512e78e4b45e4742ed197c3c5e45c549fd25f2e4187b0bc9fe30492b16b0d0bc4ef9b0f34c7003fac09a5ef1532e69430234cebd",
+    ),
+    (
+        2,
+        "da2cfbe2d8409a0f38026113884f84b50156371ae304c4430173d08a99d9fb1b983164a3770706d537f49e0c916d9f32b95cc37a95b99d857436f0232c88a965",
+    ),
+    (
+        3,
+        "33d0825dddf7ada99b0e7e307104ad07ca9cfd9692214f1561356315e784f3e5a17e364ae9dbb14cb2036df932b77f4b292761365fb328de7afdc6d8998f5fc1",
+    ),
+    (
+        127,
+        "76d2d819c92bce55fa8e092ab1bf9b9eab237a25267986cacf2b8ee14d214d730dc9a5aa2d7b596e86a1fd8fa0804c77402d2fcd45083688b218b1cdfa0dcbcb",
+    ),
+    (
+        128,
+        "72065ee4dd91c2d8509fa1fc28a37c7fc9fa7d5b3f8ad3d0d7a25626b57b1b44788d4caf806290425f9890a3a2a35a905ab4b37acfd0da6e4517b2525c9651e4",
+    ),
+    (
+        129,
+        "64475dfe7600d7171bea0b394e27c9b00d8e74dd1e416a79473682ad3dfdbb706631558055cfc8a40e07bd015a4540dcdea15883cbbf31412df1de1cd4152b91",
+    ),
+];
+
+#[test]
+fn test_keyed() {
+    for (len, expected) in KEYED_KAT.iter() {
+        let h = blake2b_keyed(seq(*len), seq(64), 64).unwrap();
+        assert_eq!(*expected, h.to_hex());
+    }
+}
+
+#[test]
+fn test_params() {
+    // Unkeyed with the default parameters is the same as blake2b.
+    let abc = ByteSeq::from("616263");
+    let h = blake2b_params(
+        abc.clone(),
+        ByteSeq::new(0),
+        64,
+        Salt::new(),
+        Personalization::new(),
+    )
+    .unwrap();
+    assert_eq!(blake2b(abc.clone()).to_hex(), h.to_hex());
+
+    // The output length is part of the parameter block, so shorter outputs
+    // are not truncations of the 64 byte output.
+    let h = blake2b_keyed(abc.clone(), ByteSeq::new(0), 32).unwrap();
+    assert_eq!(
+        "bddd813c634239723171ef3fee98579b94964e3bb1cb3e427262c8c068d52319",
+        h.to_hex()
+    );
+    let h = blake2b_keyed(abc.clone(), ByteSeq::new(0), 1).unwrap();
+    assert_eq!("6b", h.to_hex());
+
+    // key = "key", salt = 00 01 .. 0f and personalization = "personalization!"
+    let h = blake2b_params(
+        abc.clone(),
+        ByteSeq::from("6b6579"),
+        20,
+        Salt::from(seq(16)),
+        Personalization::from("706572736f6e616c697a6174696f6e21"),
+    )
+    .unwrap();
+    assert_eq!("c72efecdc5e37251764c665d99daec4528a9203b", h.to_hex());
+
+    // salt = "saltsaltsaltsalt" and personalization = "ZcashPoW" with zeros
+    let h = blake2b_params(
+        abc,
+        ByteSeq::new(0),
+        64,
+        Salt::from("73616c7473616c7473616c7473616c74"),
+        Personalization::from("5a63617368506f570000000000000000"),
+    )
+    .unwrap();
+    assert_eq!(
+        "306a4d6550dcf28f1753dcacc9dc88dc94b005e05ac8e5f67da5c5a1491f66f587d240abef46b794a2e559fed9aef4587d1c4bee7675ea9d9fdda53f00c94542",
+        h.to_hex()
+    );
+}
+
+#[test]
+fn test_params_invalid() {
+    let abc = ByteSeq::from("616263");
+    assert!(blake2b_keyed(abc.clone(), seq(65), 64).is_err());
+    assert!(blake2b_keyed(abc.clone(), seq(16), 0).is_err());
+    assert!(blake2b_keyed(abc, seq(16), 65).is_err());
+}
+
+#[test]
+fn test_empty_and_full_blocks() {
+    // The empty message is one empty final block.
+    assert_eq!(
+        "786a02f742015903c6c6fd852552d272912f4740e15847618a86e217f71f5419d25e1031afee585313896444934eb04b903a685b1448b755d56f701afe9be2ce",
+        blake2b(ByteSeq::new(0)).to_hex()
+    );
+    // Messages of exactly one and two blocks end with a full final block.
+    assert_eq!(
+        "2319e3789c47e2daa5fe807f61bec2a1a6537fa03f19ff32e87eecbfd64b7e0e8ccff439ac333b040f19b0c4ddd11a61e24ac1fe0f10a039806c5dcc0da3d115",
+        blake2b(seq(128)).to_hex()
+    );
+    assert_eq!(
+        "ec9c6b301a6c98946d742a74710e658f0243e0e6d3525f4afa94dfc2395456fa54ebe5ef0f413b5a9abfe6501dabb4b9a0fbca164d6cd80b1e79dbbed8d4202e",
+        blake2b(ByteSeq::new(256)).to_hex()
+    );
+}
+
+// https://github.com/BLAKE2/BLAKE2/blob/master/testvectors/blake2b-kat.txt
+// Keyed with key = 00 01 .. 3f for in = 00 01 .. (len - 1) for all lengths
+// from 0 to 255.
+#[test]
+fn test_kat_file() {
+    let mut count = 0;
+    for entry in include_str!("blake2b-kat.txt").split("\n\n") {
+        let lines: Vec<&str> = entry.lines().collect();
+        if lines.len() != 3 {
+            continue;
+        }
+        let input = lines[0].trim_start_matches("in:").trim();
+        let key = lines[1].trim_start_matches("key:").trim();
+        let expected = lines[2].trim_start_matches("hash:").trim();
+        let h = blake2b_keyed(ByteSeq::from(input), ByteSeq::from(key), 64).unwrap();
+        assert_eq!(expected, h.to_hex());
+        count += 1;
+    }
+    assert_eq!(256, count);
+}
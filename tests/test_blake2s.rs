@@ -0,0 +1,123 @@
+extern crate hacspec;
+use hacspec::prelude::*;
+
+use hacspecs::blake2s::*;
+
+fn seq(len: usize) -> ByteSeq {
+    let mut s = ByteSeq::new(len);
+    for i in 0..len {
+        s[i] = U8(i as u8);
+    }
+    s
+}
+
+// RFC 7693 Appendix B
+#[test]
+fn test_abc() {
+    let h = blake2s(ByteSeq::from("616263"));
+    assert_eq!(
+        "508c5e8c327c14e2e1a72ba34eeb452f37458b209ed63a294d999b4c86675982",
+        h.to_hex()
+    );
+}
+
+#[test]
+fn test_empty() {
+    assert_eq!(
+        "69217a3079908094e11121d042354a7c1f55b6482ca1a51e1b250dfd1ed0eef9",
+        blake2s(ByteSeq::new(0)).to_hex()
+    );
+}
+
+// Keyed vectors from the BLAKE2 KAT file blake2s-kat.txt with
+// key = 00 01 .. 1f and in = 00 01 .. (len - 1)
+const KEYED_KAT: [(usize, &str); 5] = [
+    (
+        0,
+        "48a8997da407876b3d79c0d92325ad3b89cbb754d86ab71aee047ad345fd2c49",
+    ),
+    (
+        1,
+        "40d15fee7c328830166ac3f918650f807e7e01e177258cdc0a39b11f598066f1",
+    ),
+    (
+        63,
+        "c65382513f07460da39833cb666c5ed82e61b9e998f4b0c4287cee56c3cc9bcd",
+    ),
+    (
+        64,
+        "8975b0577fd35566d750b362b0897a26c399136df07bababbde6203ff2954ed4",
+    ),
+    (
+        65,
+        "21fe0ceb0052be7fb0f004187cacd7de67fa6eb0938d927677f2398c132317a8",
+    ),
+];
+
+#[test]
+fn test_keyed() {
+    for (len, expected) in KEYED_KAT.iter() {
+        let h = blake2s_keyed(seq(*len), seq(32), 32).unwrap();
+        assert_eq!(*expected, h.to_hex());
+    }
+}
+
+// Deterministic sequences from the RFC 7693 Appendix E self-test
+fn selftest_seq(len: usize, seed: u32) -> ByteSeq {
+    let mut a = 0xdead_4badu32.wrapping_mul(seed);
+    let mut b = 1u32;
+    let mut s = ByteSeq::new(len);
+    for i in 0..len {
+        let t = a.wrapping_add(b);
+        a = b;
+        b = t;
+        s[i] = U8((t >> 24) as u8);
+    }
+    s
+}
+
+// RFC 7693 Appendix E
+// The hash of all unkeyed and keyed hashes for several input and output
+// lengths.
+#[test]
+fn test_selftest() {
+    let out_lens = [16, 20, 28, 32];
+    let in_lens = [0, 3, 64, 65, 255, 1024];
+    let mut hashes = ByteSeq::new(0);
+    for out_len in out_lens.iter() {
+        for in_len in in_lens.iter() {
+            let data = selftest_seq(*in_len, *in_len as u32);
+            let key = selftest_seq(*out_len, *out_len as u32);
+            let h = blake2s_keyed(data.clone(), ByteSeq::new(0), *out_len).unwrap();
+            hashes = hashes.push(h);
+            let h = blake2s_keyed(data, key, *out_len).unwrap();
+            hashes = hashes.push(h);
+        }
+    }
+    assert_eq!(
+        "6a411f08ce25adcdfb02aba641451cec53c598b24f4fc787fbdc88797f4c1dfe",
+        blake2s(hashes).to_hex()
+    );
+}
+
+#[test]
+fn test_params() {
+    // salt = 00 01 .. 07 and personalization = "WireGrd!"
+    let h = blake2s_params(
+        ByteSeq::from("616263"),
+        ByteSeq::new(0),
+        16,
+        Salt::from(seq(8)),
+        Personalization::from("5769726547726421"),
+    )
+    .unwrap();
+    assert_eq!("478ec368e8c6dbf5c2eaa47551f25119", h.to_hex());
+}
+
+#[test]
+fn test_params_invalid() {
+    let abc = ByteSeq::from("616263");
+    assert!(blake2s_keyed(abc.clone(), seq(33), 32).is_err());
+    assert!(blake2s_keyed(abc.clone(), seq(16), 0).is_err());
+    assert!(blake2s_keyed(abc, seq(16), 33).is_err());
+}
@@ -177,3 +177,118 @@ fn test_kat() {
     ]));
     kat_test(m, key, iv, exp_cipher, true);
 }
+
+struct DJBTestVector<'a> {
+    key: &'a str,
+    iv: &'a str,
+    rounds: usize,
+    keystream: &'a str,
+}
+
+// https://tools.ietf.org/html/draft-strombergson-chacha-test-vectors-00
+const DJB_KAT: [DJBTestVector; 6] = [
+    DJBTestVector {
+        key: "0000000000000000000000000000000000000000000000000000000000000000",
+        iv: "0000000000000000",
+        rounds: 8,
+        keystream: "3e00ef2f895f40d67f5bb8e81f09a5a12c840ec3ce9a7f3b181be188ef711a1e984ce172b9216f419f445367456d5619314a42a3da86b001387bfdb80e0cfe42d2aefa0deaa5c151bf0adb6c01f2a5adc0fd581259f9a2aadcf20f8fd566a26b5032ec38bbc5da98ee0c6f568b872a65a08abf251deb21bb4b56e5d8821e68aa",
+    },
+    DJBTestVector {
+        key: "0000000000000000000000000000000000000000000000000000000000000000",
+        iv: "0000000000000000",
+        rounds: 12,
+        keystream: "9bf49a6a0755f953811fce125f2683d50429c3bb49e074147e0089a52eae155f0564f879d27ae3c02ce82834acfa8c793a629f2ca0de6919610be82f411326be0bd58841203e74fe86fc71338ce0173dc628ebb719bdcbcc151585214cc089b442258dcda14cf111c602b8971b8cc843e91e46ca905151c02744a6b017e69316",
+    },
+    DJBTestVector {
+        key: "0000000000000000000000000000000000000000000000000000000000000000",
+        iv: "0000000000000000",
+        rounds: 20,
+        keystream: "76b8e0ada0f13d90405d6ae55386bd28bdd219b8a08ded1aa836efcc8b770dc7da41597c5157488d7724e03fb8d84a376a43b8f41518a11cc387b669b2ee65869f07e7be5551387a98ba977c732d080dcb0f29a048e3656912c6533e32ee7aed29b721769ce64e43d57133b074d839d531ed1f28510afb45ace10a1f4b794d6f",
+    },
+    DJBTestVector {
+        key: "c46ec1b18ce8a878725a37e780dfb7351f68ed2e194c79fbc6aebee1a667975d",
+        iv: "1ada31d5cf688221",
+        rounds: 8,
+        keystream: "838751b42d8ddd8a3d77f48825a2ba752cf4047cb308a5978ef274973be374c96ad848065871417b08f034e681fe46a93f7d5c61d1306614d4aaf257a7cff08b16f2fda170cc18a4b58a2667ed962774af792a6e7f3c77992540711a7a136d7e8a2f8d3f93816709d45a3fa5f8ce72fde15be7b841acba3a2abd557228d9fe4f",
+    },
+    DJBTestVector {
+        key: "c46ec1b18ce8a878725a37e780dfb7351f68ed2e194c79fbc6aebee1a667975d",
+        iv: "1ada31d5cf688221",
+        rounds: 12,
+        keystream: "1482072784bc6d06b4e73bdc118bc0103c7976786ca918e06986aa251f7e9cc1b2749a0a16ee83b4242d2e99b08d7c20092b80bc466c87283b61b1b39d0ffbabd94b116bc1ebdb329b9e4f620db695544a8e3d9b68473d0c975a46ad966ed631e42aff530ad5eac7d8047adfa1e5113c91f3e3b883f1d189ac1c8fe07ba5a42b",
+    },
+    DJBTestVector {
+        key: "c46ec1b18ce8a878725a37e780dfb7351f68ed2e194c79fbc6aebee1a667975d",
+        iv: "1ada31d5cf688221",
+        rounds: 20,
+        keystream: "f63a89b75c2271f9368816542ba52f06ed49241792302b00b5e8f80ae9a473afc25b218f519af0fdd406362e8d69de7f54c604a6e00f353f110f771bdca8ab92e5fbc34e60a1d9a9db17345b0a402736853bf910b060bdf1f897b6290f01d138ae2c4c90225ba9ea14d518f55929dea098ca7a6ccfe61227053c84e49a4a3332",
+    },
+];
+
+#[test]
+fn test_djb_kat() {
+    for kat in DJB_KAT.iter() {
+        let key = Key::from(kat.key);
+        let iv = DJBIV::from(kat.iv);
+        let keystream = chacha_djb(key, iv, kat.rounds, ByteSeq::new(128)).unwrap();
+        assert_eq!(kat.keystream, keystream.to_hex());
+    }
+}
+
+#[test]
+fn test_djb_invalid_rounds() {
+    let key = Key::new();
+    let iv = DJBIV::new();
+    for rounds in [0, 7, 10, 24].iter() {
+        assert!(chacha_djb(key, iv, *rounds, ByteSeq::new(64)).is_err());
+        // Also without any key stream blocks
+        assert!(chacha_djb(key, iv, *rounds, ByteSeq::new(0)).is_err());
+        assert!(block_djb(key, U64(0), iv, *rounds).is_err());
+    }
+}
+
+#[test]
+fn test_djb_counter_carry() {
+    // The 64-bit block counter carries from word 12 into word 13.
+    let key = Key::new();
+    let iv = DJBIV::new();
+    let key_block = block_djb(key, U64(0xffff_ffff), iv, 20).unwrap();
+    assert_eq!(
+        "ace4cd09e294d1912d4ad205d06f95d9",
+        ByteSeq::from(key_block.raw()).sub(0, 16).to_hex()
+    );
+    let key_block = block_djb(key, U64(0x1_0000_0000), iv, 20).unwrap();
+    assert_eq!(
+        "3db41d3aa0d329285de6f225e6e24bd5",
+        ByteSeq::from(key_block.raw()).sub(0, 16).to_hex()
+    );
+}
+
+#[test]
+fn test_counter_overflow() {
+    let key = Key::random();
+    let iv = IV::random();
+    // The last block uses counter 2^32 - 1.
+    assert!(chacha_at(key, iv, 0xffff_ffff, ByteSeq::random(64)).is_ok());
+    // The second block would wrap the counter.
+    assert!(chacha_at(key, iv, 0xffff_ffff, ByteSeq::random(65)).is_err());
+    assert!(chacha_at(key, iv, 0xffff_fffe, ByteSeq::random(129)).is_err());
+    assert!(chacha_seek(key, iv, (0xffff_ffffu64 - 1) * 64 + 10, ByteSeq::random(54)).is_ok());
+    assert!(chacha_seek(key, iv, (0xffff_ffffu64 - 1) * 64 + 10, ByteSeq::random(55)).is_err());
+    assert!(chacha_seek(key, iv, 0xffff_ffffu64 * 64, ByteSeq::new(0)).is_err());
+}
+
+#[test]
+fn test_seek() {
+    let key = Key::random();
+    let iv = IV::random();
+    let m = ByteSeq::random(300);
+    let c = chacha(key, iv, m.clone()).unwrap();
+    for &offset in [0usize, 1, 63, 64, 100, 128, 299].iter() {
+        let len = m.len() - offset;
+        let m_dec = chacha_seek(key, iv, offset as u64, c.sub(offset, len)).unwrap();
+        assert_bytes_eq!(m.sub(offset, len), m_dec);
+    }
+    let c_at = chacha_at(key, iv, 3, m.sub(128, 172)).unwrap();
+    assert_bytes_eq!(c.sub(128, 172), c_at);
+}
@@ -68,3 +68,55 @@ fn test_kat3() {
         ecdh(s, u, expected);
     }
 }
+
+#[test]
+fn test_x25519_zero() {
+    let s =
+        SerializedScalar::from("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f");
+    // Points of small order
+    let low_order = [
+        "0000000000000000000000000000000000000000000000000000000000000000",
+        "0100000000000000000000000000000000000000000000000000000000000000",
+        "e0eb7a7c3b41b8ae1656e3faf19fc46ada098deb9c32b1fd866205165f49b800",
+        "5f9c95bca3508c24b1d0b1559c83ef5b04445cc4581c8e86d8224eddd09f1157",
+        "ecffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f",
+    ];
+    for u in low_order.iter() {
+        assert!(x25519(s, SerializedPoint::from(*u)).is_err());
+    }
+    for kat in KAT.iter() {
+        let r = x25519(SerializedScalar::from(kat.0), SerializedPoint::from(kat.1)).unwrap();
+        assert_bytes_eq!(SerializedPoint::from(kat.2), r);
+    }
+}
+
+fn iterate(n: usize) -> SerializedPoint {
+    let mut k =
+        SerializedPoint::from("0900000000000000000000000000000000000000000000000000000000000000");
+    let mut u = k;
+    for _ in 0..n {
+        let r = x25519(SerializedScalar::from_sub(k, 0..32), u).unwrap();
+        u = k;
+        k = r;
+    }
+    k
+}
+
+// https://tools.ietf.org/html/rfc7748#section-5.2
+#[test]
+fn test_iterations() {
+    let expected =
+        SerializedPoint::from("422c8e7a6227d7bca1350b3e2bb7279f7897b87bb6854b783c60e80311ae3079");
+    assert_bytes_eq!(expected, iterate(1));
+    let expected =
+        SerializedPoint::from("684cf59ba83309552800ef566f2f4d3c1c3887c49360e3875f2eb94d99532c51");
+    assert_bytes_eq!(expected, iterate(1_000));
+}
+
+#[test]
+#[ignore]
+fn test_iterations_million() {
+    let expected =
+        SerializedPoint::from("7c3911e0ab2586fd864497297e575e6f3bc601c0883c30df5f4dd2d24f887424");
+    assert_bytes_eq!(expected, iterate(1_000_000));
+}
@@ -0,0 +1,692 @@
+extern crate hacspec;
+use hacspec::prelude::*;
+
+extern crate hacspecs;
+use hacspecs::ecdsa::*;
+use hacspecs::p256::{self, FieldElement, P256Scalar};
+use hacspecs::sha2;
+
+struct Rfc6979TestVector<'a> {
+    msg: &'a str,
+    k: &'a str,
+    r: &'a str,
+    s: &'a str,
+}
+
+// https://tools.ietf.org/html/rfc6979#appendix-A.2.5
+const SK: &str = "c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721";
+const PK_X: &str = "60fed4ba255a9d31c961eb74c6356d68c049b8923b61fa6ce669622e60f29fb6";
+const PK_Y: &str = "7903fe1008b8bc99a41ae9e95628bc64f2f1b20c2d7e9f5177a3c294d4462299";
+const RFC6979_KAT: [Rfc6979TestVector; 2] = [
+    Rfc6979TestVector {
+        // "sample"
+        msg: "73616d706c65",
+        k: "a6e3c57dd01abe90086538398355dd4c3b17aa873382b0f24d6129493d8aad60",
+        r: "efd48b2aacb6a8fd1140dd9cd45e81d69d2c877b56aaf991c34d0ea84eaf3716",
+        s: "f7cb1c942d657c41d436c7a1b6e29f65f3e900dbb9aff4064dc4ab2f843acda8",
+    },
+    Rfc6979TestVector {
+        // "test"
+        msg: "74657374",
+        k: "d16b6ae827f17175e040871a1c7ec3500192c4c92677336ec2537acaee0008e0",
+        r: "f1abb023518351cd71d881567b1ea663ed3efcf6c5132b354f28d3b0b7d38367",
+        s: "019f4113742a2b14bd25926b49c649155f267e60d3814b4c0cc84250e46f0083",
+    },
+];
+
+#[test]
+fn test_rfc6979() {
+    let sk = P256Scalar::from_hex(SK);
+    let pk = public_key(sk).unwrap();
+    assert_eq!(
+        pk,
+        (FieldElement::from_hex(PK_X), FieldElement::from_hex(PK_Y))
+    );
+    for kat in RFC6979_KAT.iter() {
+        let msg = ByteSeq::from(kat.msg);
+        let k = rfc6979_nonce(sk, sha2::hash(msg.clone()));
+        assert_eq!(k, P256Scalar::from_hex(kat.k));
+
+        let sig = sign(sk, msg.clone(), false).unwrap();
+        let expected = (P256Scalar::from_hex(kat.r), P256Scalar::from_hex(kat.s));
+        assert_eq!(sig, expected);
+        assert_eq!(
+            format!("{}{}", kat.r, kat.s),
+            signature_to_bytes(sig).to_hex()
+        );
+        assert!(verify(pk, msg.clone(), sig).is_ok());
+        assert!(verify(pk, ByteSeq::from("00"), sig).is_err());
+    }
+}
+
+#[test]
+fn test_low_s() {
+    let sk = P256Scalar::from_hex(SK);
+    let pk = public_key(sk).unwrap();
+    let msg = ByteSeq::from(RFC6979_KAT[0].msg);
+    // s of the first vector is in the upper half and gets negated.
+    let high = sign(sk, msg.clone(), false).unwrap();
+    let low = sign(sk, msg.clone(), true).unwrap();
+    assert_eq!(low.0, high.0);
+    assert_eq!(low.1, P256Scalar::from_literal(0) - high.1);
+    assert_eq!(normalize_s(low), low);
+    assert!(verify(pk, msg, low).is_ok());
+
+    // s of the second vector already is in the lower half.
+    let msg = ByteSeq::from(RFC6979_KAT[1].msg);
+    let sig = sign(sk, msg.clone(), false).unwrap();
+    assert_eq!(sign(sk, msg, true).unwrap(), sig);
+}
+
+#[test]
+fn test_der() {
+    let sig = (
+        P256Scalar::from_hex(RFC6979_KAT[0].r),
+        P256Scalar::from_hex(RFC6979_KAT[0].s),
+    );
+    let der = signature_to_der(sig);
+    assert_eq!(
+        "3046022100efd48b2aacb6a8fd1140dd9cd45e81d69d2c877b56aaf991c34d0ea84eaf3716022100f7cb1c942d657c41d436c7a1b6e29f65f3e900dbb9aff4064dc4ab2f843acda8",
+        der.to_hex()
+    );
+    assert_eq!(signature_from_der(der).unwrap(), sig);
+
+    let sig = (
+        P256Scalar::from_hex(RFC6979_KAT[1].r),
+        P256Scalar::from_hex(RFC6979_KAT[1].s),
+    );
+    let der = signature_to_der(sig);
+    assert_eq!(
+        "3045022100f1abb023518351cd71d881567b1ea663ed3efcf6c5132b354f28d3b0b7d383670220019f4113742a2b14bd25926b49c649155f267e60d3814b4c0cc84250e46f0083",
+        der.to_hex()
+    );
+    assert_eq!(signature_from_der(der).unwrap(), sig);
+
+    let small = (P256Scalar::from_literal(1), P256Scalar::from_literal(0x80));
+    let der = signature_to_der(small);
+    assert_eq!("300702010102020080", der.to_hex());
+    assert_eq!(signature_from_der(der).unwrap(), small);
+}
+
+#[test]
+fn test_der_invalid() {
+    let invalid = [
+        // Empty
+        "",
+        // Wrong tag
+        "3106020101020101",
+        // Wrong length
+        "3007020101020101",
+        // Trailing data
+        "300602010102010100",
+        // Negative s
+        "3006020101020181",
+        // Non-minimal s
+        "300702010102020001",
+        // Zero s
+        "3006020101020100",
+        // s = n
+        "3026020101022100ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551",
+    ];
+    for der in invalid.iter() {
+        assert!(signature_from_der(ByteSeq::from(*der)).is_err());
+    }
+}
+
+struct SigGenTestVector<'a> {
+    msg: &'a str,
+    d: &'a str,
+    q_x: &'a str,
+    q_y: &'a str,
+    k: &'a str,
+    r: &'a str,
+    s: &'a str,
+}
+
+// NIST CAVP FIPS 186-3 ECDSA SigGen.txt, [P-256,SHA-256], COUNT = 0, 1, 2
+// https://csrc.nist.gov/projects/cryptographic-algorithm-validation-program/digital-signatures
+const SIGGEN_KAT: [SigGenTestVector; 3] = [
+    SigGenTestVector {
+        msg: "5905238877c77421f73e43ee3da6f2d9e2ccad5fc942dcec0cbd25482935faaf416983fe165b1a045ee2bcd2e6dca3bdf46c4310a7461f9a37960ca672d3feb5473e253605fb1ddfd28065b53cb5858a8ad28175bf9bd386a5e471ea7a65c17cc934a9d791e91491eb3754d03799790fe2d308d16146d5c9b0d0debd97d79ce8",
+        d: "519b423d715f8b581f4fa8ee59f4771a5b44c8130b4e3eacca54a56dda72b464",
+        q_x: "1ccbe91c075fc7f4f033bfa248db8fccd3565de94bbfb12f3c59ff46c271bf83",
+        q_y: "ce4014c68811f9a21a1fdb2c0e6113e06db7ca93b7404e78dc7ccd5ca89a4ca9",
+        k: "94a1bbb14b906a61a280f245f9e93c7f3b4a6247824f5d33b9670787642a68de",
+        r: "f3ac8061b514795b8843e3d6629527ed2afd6b1f6a555a7acabb5e6f79c8c2ac",
+        s: "8bf77819ca05a6b2786c76262bf7371cef97b218e96f175a3ccdda2acc058903",
+    },
+    SigGenTestVector {
+        msg: "c35e2f092553c55772926bdbe87c9796827d17024dbb9233a545366e2e5987dd344deb72df987144b8c6c43bc41b654b94cc856e16b96d7a821c8ec039b503e3d86728c494a967d83011a0e090b5d54cd47f4e366c0912bc808fbb2ea96efac88fb3ebec9342738e225f7c7c2b011ce375b56621a20642b4d36e060db4524af1",
+        d: "0f56db78ca460b055c500064824bed999a25aaf48ebb519ac201537b85479813",
+        q_x: "e266ddfdc12668db30d4ca3e8f7749432c416044f2d2b8c10bf3d4012aeffa8a",
+        q_y: "bfa86404a2e9ffe67d47c587ef7a97a7f456b863b4d02cfc6928973ab5b1cb39",
+        k: "6d3e71882c3b83b156bb14e0ab184aa9fb728068d3ae9fac421187ae0b2f34c6",
+        r: "976d3a4e9d23326dc0baa9fa560b7c4e53f42864f508483a6473b6a11079b2db",
+        s: "1b766e9ceb71ba6c01dcd46e0af462cd4cfa652ae5017d4555b8eeefe36e1932",
+    },
+    SigGenTestVector {
+        msg: "3c054e333a94259c36af09ab5b4ff9beb3492f8d5b4282d16801daccb29f70fe61a0b37ffef5c04cd1b70e85b1f549a1c4dc672985e50f43ea037efa9964f096b5f62f7ffdf8d6bfb2cc859558f5a393cb949dbd48f269343b5263dcdb9c556eca074f2e98e6d94c2c29a677afaf806edf79b15a3fcd46e7067b7669f83188ee",
+        d: "e283871239837e13b95f789e6e1af63bf61c918c992e62bca040d64cad1fc2ef",
+        q_x: "74ccd8a62fba0e667c50929a53f78c21b8ff0c3c737b0b40b1750b2302b0bde8",
+        q_y: "29074e21f3a0ef88b9efdf10d06aa4c295cc1671f758ca0e4cd108803d0f2614",
+        k: "ad5e887eb2b380b8d8280ad6e5ff8a60f4d26243e0124c2f31a297b5d0835de2",
+        r: "35fb60f5ca0f3ca08542fb3cc641c8263a2cab7a90ee6a5e1583fac2bb6f6bd1",
+        s: "ee59d81bc9db1055cc0ed97b159d8784af04e98511d0a9a407b99bb292572e96",
+    },
+];
+
+#[test]
+fn test_siggen() {
+    for kat in SIGGEN_KAT.iter() {
+        let d = P256Scalar::from_hex(kat.d);
+        let pk = public_key(d).unwrap();
+        assert_eq!(
+            pk,
+            (
+                FieldElement::from_hex(kat.q_x),
+                FieldElement::from_hex(kat.q_y)
+            )
+        );
+
+        // Sign with the nonce k of the test vector.
+        let msg = ByteSeq::from(kat.msg);
+        let k = P256Scalar::from_hex(kat.k);
+        let (x, _) = p256::point_mul_base(p256::Scalar::from_hex(kat.k)).unwrap();
+        let r = P256Scalar::from_byte_seq_be(x.to_byte_seq_be());
+        let e = P256Scalar::from_byte_seq_be(ByteSeq::from(sha2::hash(msg.clone()).raw()));
+        let sig = (r, k.inv() * (e + r * d));
+        assert_eq!(
+            sig,
+            (P256Scalar::from_hex(kat.r), P256Scalar::from_hex(kat.s))
+        );
+        assert!(verify(pk, msg.clone(), sig).is_ok());
+        // Wrong public key
+        let other = (FieldElement::from_hex(PK_X), FieldElement::from_hex(PK_Y));
+        assert!(verify(other, msg.clone(), sig).is_err());
+        // Not on the curve
+        let invalid = (pk.0, pk.1 + FieldElement::from_literal(1));
+        assert!(verify(invalid, msg.clone(), sig).is_err());
+    }
+}
+
+struct SigVerTestVector<'a> {
+    msg: &'a str,
+    q_x: &'a str,
+    q_y: &'a str,
+    r: &'a str,
+    s: &'a str,
+    result: bool,
+}
+
+// NIST CAVP FIPS 186-3 ECDSA SigVer.rsp, [P-256,SHA-256], all 15 vectors
+// https://csrc.nist.gov/projects/cryptographic-algorithm-validation-program/digital-signatures
+const SIGVER_KAT: [SigVerTestVector; 15] = [
+    SigVerTestVector {
+        msg: "e4796db5f785f207aa30d311693b3702821dff1168fd2e04c0836825aefd850d9aa60326d88cde1a23c7745351392ca2288d632c264f197d05cd424a30336c19fd09bb229654f0222fcb881a4b35c290a093ac159ce13409111ff0358411133c24f5b8e2090d6db6558afc36f06ca1f6ef779785adba68db27a409859fc4c4a0",
+        q_x: "87f8f2b218f49845f6f10eec3877136269f5c1a54736dbdf69f89940cad41555",
+        q_y: "e15f369036f49842fac7a86c8a2b0557609776814448b8f5e84aa9f4395205e9",
+        r: "d19ff48b324915576416097d2544f7cbdf8768b1454ad20e0baac50e211f23b0",
+        s: "a3e81e59311cdfff2d4784949f7a2cb50ba6c3a91fa54710568e61aca3e847c6",
+        // S changed
+        result: false,
+    },
+    SigVerTestVector {
+        msg: "069a6e6b93dfee6df6ef6997cd80dd2182c36653cef10c655d524585655462d683877f95ecc6d6c81623d8fac4e900ed0019964094e7de91f1481989ae1873004565789cbf5dc56c62aedc63f62f3b894c9c6f7788c8ecaadc9bd0e81ad91b2b3569ea12260e93924fdddd3972af5273198f5efda0746219475017557616170e",
+        q_x: "5cf02a00d205bdfee2016f7421807fc38ae69e6b7ccd064ee689fc1a94a9f7d2",
+        q_y: "ec530ce3cc5c9d1af463f264d685afe2b4db4b5828d7e61b748930f3ce622a85",
+        r: "dc23d130c6117fb5751201455e99f36f59aba1a6a21cf2d0e7481a97451d6693",
+        s: "d6ce7708c18dbf35d4f8aa7240922dc6823f2e7058cbc1484fcad1599db5018c",
+        // R changed
+        result: false,
+    },
+    SigVerTestVector {
+        msg: "df04a346cf4d0e331a6db78cca2d456d31b0a000aa51441defdb97bbeb20b94d8d746429a393ba88840d661615e07def615a342abedfa4ce912e562af714959896858af817317a840dcff85a057bb91a3c2bf90105500362754a6dd321cdd86128cfc5f04667b57aa78c112411e42da304f1012d48cd6a7052d7de44ebcc01de",
+        q_x: "2ddfd145767883ffbb0ac003ab4a44346d08fa2570b3120dcce94562422244cb",
+        q_y: "5f70c7d11ac2b7a435ccfbbae02c3df1ea6b532cc0e9db74f93fffca7c6f9a64",
+        r: "9913111cff6f20c5bf453a99cd2c2019a4e749a49724a08774d14e4c113edda8",
+        s: "9467cd4cd21ecb56b0cab0a9a453b43386845459127a952421f5c6382866c5cc",
+        // Q changed
+        result: false,
+    },
+    SigVerTestVector {
+        msg: "e1130af6a38ccb412a9c8d13e15dbfc9e69a16385af3c3f1e5da954fd5e7c45fd75e2b8c36699228e92840c0562fbf3772f07e17f1add56588dd45f7450e1217ad239922dd9c32695dc71ff2424ca0dec1321aa47064a044b7fe3c2b97d03ce470a592304c5ef21eed9f93da56bb232d1eeb0035f9bf0dfafdcc4606272b20a3",
+        q_x: "e424dc61d4bb3cb7ef4344a7f8957a0c5134e16f7a67c074f82e6e12f49abf3c",
+        q_y: "970eed7aa2bc48651545949de1dddaf0127e5965ac85d1243d6f60e7dfaee927",
+        r: "bf96b99aa49c705c910be33142017c642ff540c76349b9dab72f981fd9347f4f",
+        s: "17c55095819089c2e03b9cd415abdf12444e323075d98f31920b9e0f57ec871c",
+        result: true,
+    },
+    SigVerTestVector {
+        msg: "73c5f6a67456ae48209b5f85d1e7de7758bf235300c6ae2bdceb1dcb27a7730fb68c950b7fcada0ecc4661d3578230f225a875e69aaa17f1e71c6be5c831f22663bac63d0c7a9635edb0043ff8c6f26470f02a7bc56556f1437f06dfa27b487a6c4290d8bad38d4879b334e341ba092dde4e4ae694a9c09302e2dbf443581c08",
+        q_x: "e0fc6a6f50e1c57475673ee54e3a57f9a49f3328e743bf52f335e3eeaa3d2864",
+        q_y: "7f59d689c91e463607d9194d99faf316e25432870816dde63f5d4b373f12f22a",
+        r: "1d75830cd36f4c9aa181b2c4221e87f176b7f05b7c87824e82e396c88315c407",
+        s: "cb2acb01dac96efc53a32d4a0d85d0c2e48955214783ecf50a4f0414a319c05a",
+        result: true,
+    },
+    SigVerTestVector {
+        msg: "666036d9b4a2426ed6585a4e0fd931a8761451d29ab04bd7dc6d0c5b9e38e6c2b263ff6cb837bd04399de3d757c6c7005f6d7a987063cf6d7e8cb38a4bf0d74a282572bd01d0f41e3fd066e3021575f0fa04f27b700d5b7ddddf50965993c3f9c7118ed78888da7cb221849b3260592b8e632d7c51e935a0ceae15207bedd548",
+        q_x: "a849bef575cac3c6920fbce675c3b787136209f855de19ffe2e8d29b31a5ad86",
+        q_y: "bf5fe4f7858f9b805bd8dcc05ad5e7fb889de2f822f3d8b41694e6c55c16b471",
+        r: "25acc3aa9d9e84c7abf08f73fa4195acc506491d6fc37cb9074528a7db87b9d6",
+        s: "9b21d5b5259ed3f2ef07dfec6cc90d3a37855d1ce122a85ba6a333f307d31537",
+        // R changed
+        result: false,
+    },
+    SigVerTestVector {
+        msg: "7e80436bce57339ce8da1b5660149a20240b146d108deef3ec5da4ae256f8f894edcbbc57b34ce37089c0daa17f0c46cd82b5a1599314fd79d2fd2f446bd5a25b8e32fcf05b76d644573a6df4ad1dfea707b479d97237a346f1ec632ea5660efb57e8717a8628d7f82af50a4e84b11f21bdff6839196a880ae20b2a0918d58cd",
+        q_x: "3dfb6f40f2471b29b77fdccba72d37c21bba019efa40c1c8f91ec405d7dcc5df",
+        q_y: "f22f953f1e395a52ead7f3ae3fc47451b438117b1e04d613bc8555b7d6e6d1bb",
+        r: "548886278e5ec26bed811dbb72db1e154b6f17be70deb1b210107decb1ec2a5a",
+        s: "e93bfebd2f14f3d827ca32b464be6e69187f5edbd52def4f96599c37d58eee75",
+        // Q changed
+        result: false,
+    },
+    SigVerTestVector {
+        msg: "1669bfb657fdc62c3ddd63269787fc1c969f1850fb04c933dda063ef74a56ce13e3a649700820f0061efabf849a85d474326c8a541d99830eea8131eaea584f22d88c353965dabcdc4bf6b55949fd529507dfb803ab6b480cd73ca0ba00ca19c438849e2cea262a1c57d8f81cd257fb58e19dec7904da97d8386e87b84948169",
+        q_x: "69b7667056e1e11d6caf6e45643f8b21e7a4bebda463c7fdbc13bc98efbd0214",
+        q_y: "d3f9b12eb46c7c6fda0da3fc85bc1fd831557f9abc902a3be3cb3e8be7d1aa2f",
+        r: "288f7a1cd391842cce21f00e6f15471c04dc182fe4b14d92dc18910879799790",
+        s: "247b3c4e89a3bcadfea73c7bfd361def43715fa382b8c3edf4ae15d6e55e9979",
+        // Message changed
+        result: false,
+    },
+    SigVerTestVector {
+        msg: "3fe60dd9ad6caccf5a6f583b3ae65953563446c4510b70da115ffaa0ba04c076115c7043ab8733403cd69c7d14c212c655c07b43a7c71b9a4cffe22c2684788ec6870dc2013f269172c822256f9e7cc674791bf2d8486c0f5684283e1649576efc982ede17c7b74b214754d70402fb4bb45ad086cf2cf76b3d63f7fce39ac970",
+        q_x: "bf02cbcf6d8cc26e91766d8af0b164fc5968535e84c158eb3bc4e2d79c3cc682",
+        q_y: "069ba6cb06b49d60812066afa16ecf7b51352f2c03bd93ec220822b1f3dfba03",
+        r: "f5acb06c59c2b4927fb852faa07faf4b1852bbb5d06840935e849c4d293d1bad",
+        s: "049dab79c89cc02f1484c437f523e080a75f134917fda752f2d5ca397addfe5d",
+        // S changed
+        result: false,
+    },
+    SigVerTestVector {
+        msg: "983a71b9994d95e876d84d28946a041f8f0a3f544cfcc055496580f1dfd4e312a2ad418fe69dbc61db230cc0c0ed97e360abab7d6ff4b81ee970a7e97466acfd9644f828ffec538abc383d0e92326d1c88c55e1f46a668a039beaa1be631a89129938c00a81a3ae46d4aecbf9707f764dbaccea3ef7665e4c4307fa0b0a3075c",
+        q_x: "224a4d65b958f6d6afb2904863efd2a734b31798884801fcab5a590f4d6da9de",
+        q_y: "178d51fddada62806f097aa615d33b8f2404e6b1479f5fd4859d595734d6d2b9",
+        r: "87b93ee2fecfda54deb8dff8e426f3c72c8864991f8ec2b3205bb3b416de93d2",
+        s: "4044a24df85be0cc76f21a4430b75b8e77b932a87f51e4eccbc45c263ebf8f66",
+        // R changed
+        result: false,
+    },
+    SigVerTestVector {
+        msg: "4a8c071ac4fd0d52faa407b0fe5dab759f7394a5832127f2a3498f34aac287339e043b4ffa79528faf199dc917f7b066ad65505dab0e11e6948515052ce20cfdb892ffb8aa9bf3f1aa5be30a5bbe85823bddf70b39fd7ebd4a93a2f75472c1d4f606247a9821f1a8c45a6cb80545de2e0c6c0174e2392088c754e9c8443eb5af",
+        q_x: "43691c7795a57ead8c5c68536fe934538d46f12889680a9cb6d055a066228369",
+        q_y: "f8790110b3c3b281aa1eae037d4f1234aff587d903d93ba3af225c27ddc9ccac",
+        r: "8acd62e8c262fa50dd9840480969f4ef70f218ebf8ef9584f199031132c6b1ce",
+        s: "cfca7ed3d4347fb2a29e526b43c348ae1ce6c60d44f3191b6d8ea3a2d9c92154",
+        // S changed
+        result: false,
+    },
+    SigVerTestVector {
+        msg: "0a3a12c3084c865daf1d302c78215d39bfe0b8bf28272b3c0b74beb4b7409db0718239de700785581514321c6440a4bbaea4c76fa47401e151e68cb6c29017f0bce4631290af5ea5e2bf3ed742ae110b04ade83a5dbd7358f29a85938e23d87ac8233072b79c94670ff0959f9c7f4517862ff829452096c78f5f2e9a7e4e9216",
+        q_x: "9157dbfcf8cf385f5bb1568ad5c6e2a8652ba6dfc63bc1753edf5268cb7eb596",
+        q_y: "972570f4313d47fc96f7c02d5594d77d46f91e949808825b3d31f029e8296405",
+        r: "dfaea6f297fa320b707866125c2a7d5d515b51a503bee817de9faa343cc48eeb",
+        s: "8f780ad713f9c3e5a4f7fa4c519833dfefc6a7432389b1e4af463961f09764f2",
+        // Message changed
+        result: false,
+    },
+    SigVerTestVector {
+        msg: "785d07a3c54f63dca11f5d1a5f496ee2c2f9288e55007e666c78b007d95cc28581dce51f490b30fa73dc9e2d45d075d7e3a95fb8a9e1465ad191904124160b7c60fa720ef4ef1c5d2998f40570ae2a870ef3e894c2bc617d8a1dc85c3c55774928c38789b4e661349d3f84d2441a3b856a76949b9f1f80bc161648a1cad5588e",
+        q_x: "072b10c081a4c1713a294f248aef850e297991aca47fa96a7470abe3b8acfdda",
+        q_y: "9581145cca04a0fb94cedce752c8f0370861916d2a94e7c647c5373ce6a4c8f5",
+        r: "09f5483eccec80f9d104815a1be9cc1a8e5b12b6eb482a65c6907b7480cf4f19",
+        s: "a4f90e560c5e4eb8696cb276e5165b6a9d486345dedfb094a76e8442d026378d",
+        // Q changed
+        result: false,
+    },
+    SigVerTestVector {
+        msg: "76f987ec5448dd72219bd30bf6b66b0775c80b394851a43ff1f537f140a6e7229ef8cd72ad58b1d2d20298539d6347dd5598812bc65323aceaf05228f738b5ad3e8d9fe4100fd767c2f098c77cb99c2992843ba3eed91d32444f3b6db6cd212dd4e5609548f4bb62812a920f6e2bf1581be1ebeebdd06ec4e971862cc42055ca",
+        q_x: "09308ea5bfad6e5adf408634b3d5ce9240d35442f7fe116452aaec0d25be8c24",
+        q_y: "f40c93e023ef494b1c3079b2d10ef67f3170740495ce2cc57f8ee4b0618b8ee5",
+        r: "5cc8aa7c35743ec0c23dde88dabd5e4fcd0192d2116f6926fef788cddb754e73",
+        s: "9c9c045ebaa1b828c32f82ace0d18daebf5e156eb7cbfdc1eff4399a8a900ae7",
+        // Message changed
+        result: false,
+    },
+    SigVerTestVector {
+        msg: "60cd64b2cd2be6c33859b94875120361a24085f3765cb8b2bf11e026fa9d8855dbe435acf7882e84f3c7857f96e2baab4d9afe4588e4a82e17a78827bfdb5ddbd1c211fbc2e6d884cddd7cb9d90d5bf4a7311b83f352508033812c776a0e00c003c7e0d628e50736c7512df0acfa9f2320bd102229f46495ae6d0857cc452a84",
+        q_x: "2d98ea01f754d34bbc3003df5050200abf445ec728556d7ed7d5c54c55552b6d",
+        q_y: "9b52672742d637a32add056dfd6d8792f2a33c2e69dafabea09b960bc61e230a",
+        r: "06108e525f845d0155bf60193222b3219c98e3d49424c2fb2a0987f825c17959",
+        s: "62b5cdd591e5b507e560167ba8f6f7cda74673eb315680cb89ccbc4eec477dce",
+        result: true,
+    },
+];
+
+#[test]
+fn test_sigver() {
+    for kat in SIGVER_KAT.iter() {
+        let pk = (
+            FieldElement::from_hex(kat.q_x),
+            FieldElement::from_hex(kat.q_y),
+        );
+        let sig = (P256Scalar::from_hex(kat.r), P256Scalar::from_hex(kat.s));
+        let valid = verify(pk, ByteSeq::from(kat.msg), sig).is_ok();
+        assert_eq!(kat.result, valid);
+    }
+}
+
+// https://tools.ietf.org/html/rfc6979#appendix-A.2.6
+#[test]
+fn test_rfc6979_p384() {
+    use hacspecs::ecdsa::p384::*;
+    use hacspecs::p384::{FieldElement, P384Scalar};
+
+    let kat = [
+        Rfc6979TestVector {
+            // "sample"
+            msg: "73616d706c65",
+            k: "94ed910d1a099dad3254e9242ae85abde4ba15168eaf0ca87a555fd56d10fbca2907e3e83ba95368623b8c4686915cf9",
+            r: "94edbb92a5ecb8aad4736e56c691916b3f88140666ce9fa73d64c4ea95ad133c81a648152e44acf96e36dd1e80fabe46",
+            s: "99ef4aeb15f178cea1fe40db2603138f130e740a19624526203b6351d0a3a94fa329c145786e679e7b82c71a38628ac8",
+        },
+        Rfc6979TestVector {
+            // "test"
+            msg: "74657374",
+            k: "015ee46a5bf88773ed9123a5ab0807962d193719503c527b031b4c2d225092ada71f4a459bc0da98adb95837db8312ea",
+            r: "8203b63d3c853e8d77227fb377bcf7b7b772e97892a80f36ab775d509d7a5feb0542a7f0812998da8f1dd3ca3cf023db",
+            s: "ddd0760448d42d8a43af45af836fce4de8be06b485e9b61b827c2f13173923e06a739f040649a667bf3b828246baa5a5",
+        },
+    ];
+    let sk = P384Scalar::from_hex("6b9d3dad2e1b8c1c05b19875b6659f4de23c3b667bf297ba9aa47740787137d896d5724e4c70a825f872c9ea60d2edf5");
+    let pk = public_key(sk).unwrap();
+    assert_eq!(
+        pk,
+        (
+            FieldElement::from_hex("ec3a4e415b4e19a4568618029f427fa5da9a8bc4ae92e02e06aae5286b300c64def8f0ea9055866064a254515480bc13"),
+            FieldElement::from_hex("8015d9b72d7d57244ea8ef9ac0c621896708a59367f9dfb9f54ca84b3f1c9db1288b231c3ae0d4fe7344fd2533264720")
+        )
+    );
+    for kat in kat.iter() {
+        let msg = ByteSeq::from(kat.msg);
+        let k = rfc6979_nonce(sk, sha2::sha384(msg.clone()));
+        assert_eq!(k, P384Scalar::from_hex(kat.k));
+
+        let sig = sign(sk, msg.clone(), false).unwrap();
+        assert_eq!(
+            sig,
+            (P384Scalar::from_hex(kat.r), P384Scalar::from_hex(kat.s))
+        );
+        assert_eq!(
+            format!("{}{}", kat.r, kat.s),
+            signature_to_bytes(sig).to_hex()
+        );
+        assert_eq!(signature_from_bytes(signature_to_bytes(sig)).unwrap(), sig);
+        assert!(verify(pk, msg.clone(), sig).is_ok());
+        assert!(verify(pk, ByteSeq::from("00"), sig).is_err());
+        assert!(verify(pk, msg.clone(), normalize_s(sig)).is_ok());
+    }
+
+    // DER encoding from OpenSSL
+    let sig = (
+        P384Scalar::from_hex(kat[0].r),
+        P384Scalar::from_hex(kat[0].s),
+    );
+    let der = signature_to_der(sig);
+    assert_eq!(
+        "306602310094edbb92a5ecb8aad4736e56c691916b3f88140666ce9fa73d64c4ea95ad133c81a648152e44acf96e36dd1e80fabe4602310099ef4aeb15f178cea1fe40db2603138f130e740a19624526203b6351d0a3a94fa329c145786e679e7b82c71a38628ac8",
+        der.to_hex()
+    );
+    assert_eq!(signature_from_der(der).unwrap(), sig);
+}
+
+// NIST CAVP FIPS 186-3 ECDSA SigGen.txt, [P-384,SHA-384], COUNT = 0
+#[test]
+fn test_siggen_p384() {
+    use hacspecs::ecdsa::p384::*;
+    use hacspecs::p384::{self, FieldElement, P384Scalar};
+
+    let kat = SigGenTestVector {
+        msg: "6b45d88037392e1371d9fd1cd174e9c1838d11c3d6133dc17e65fa0c485dcca9f52d41b60161246039e42ec784d49400bffdb51459f5de654091301a09378f93464d52118b48d44b30d781eb1dbed09da11fb4c818dbd442d161aba4b9edc79f05e4b7e401651395b53bd8b5bd3f2aaa6a00877fa9b45cadb8e648550b4c6cbe",
+        d: "201b432d8df14324182d6261db3e4b3f46a8284482d52e370da41e6cbdf45ec2952f5db7ccbce3bc29449f4fb080ac97",
+        q_x: "c2b47944fb5de342d03285880177ca5f7d0f2fcad7678cce4229d6e1932fcac11bfc3c3e97d942a3c56bf34123013dbf",
+        q_y: "37257906a8223866eda0743c519616a76a758ae58aee81c5fd35fbf3a855b7754a36d4a0672df95d6c44a81cf7620c2d",
+        k: "dcedabf85978e090f733c6e16646fa34df9ded6e5ce28c6676a00f58a25283db8885e16ce5bf97f917c81e1f25c9c771",
+        r: "50835a9251bad008106177ef004b091a1e4235cd0da84fff54542b0ed755c1d6f251609d14ecf18f9e1ddfe69b946e32",
+        s: "0475f3d30c6463b646e8d3bf2455830314611cbde404be518b14464fdb195fdcc92eb222e61f426a4a592c00a6a89721",
+    };
+    let d = P384Scalar::from_hex(kat.d);
+    let pk = public_key(d).unwrap();
+    assert_eq!(
+        pk,
+        (
+            FieldElement::from_hex(kat.q_x),
+            FieldElement::from_hex(kat.q_y)
+        )
+    );
+
+    // Sign with the nonce k of the test vector.
+    let msg = ByteSeq::from(kat.msg);
+    let k = P384Scalar::from_hex(kat.k);
+    let (x, _) = p384::point_mul_base(p384::Scalar::from_hex(kat.k)).unwrap();
+    let r = P384Scalar::from_byte_seq_be(x.to_byte_seq_be());
+    let e = P384Scalar::from_byte_seq_be(ByteSeq::from(sha2::sha384(msg.clone()).raw()));
+    let sig = (r, k.inv() * (e + r * d));
+    assert_eq!(
+        sig,
+        (P384Scalar::from_hex(kat.r), P384Scalar::from_hex(kat.s))
+    );
+    assert!(verify(pk, msg.clone(), sig).is_ok());
+
+    // Failure cases in the style of SigVer, derived from the vector above.
+    let mut other_msg = msg.clone();
+    other_msg[0] = other_msg[0] ^ U8(1);
+    assert!(verify(pk, other_msg, sig).is_err());
+    let one = P384Scalar::from_literal(1);
+    assert!(verify(pk, msg.clone(), (sig.0 + one, sig.1)).is_err());
+    assert!(verify(pk, msg.clone(), (sig.0, sig.1 + one)).is_err());
+    let other_pk = public_key(d + one).unwrap();
+    assert!(verify(other_pk, msg.clone(), sig).is_err());
+}
+
+// https://tools.ietf.org/html/rfc6979#appendix-A.2.7
+#[test]
+fn test_rfc6979_p521() {
+    use hacspecs::ecdsa::p521::*;
+    use hacspecs::p521::{FieldElement, P521Scalar};
+
+    let kat = [
+        Rfc6979TestVector {
+            // "sample"
+            msg: "73616d706c65",
+            k: "01dae2ea071f8110dc26882d4d5eae0621a3256fc8847fb9022e2b7d28e6f10198b1574fdd03a9053c08a1854a168aa5a57470ec97dd5ce090124ef52a2f7ecbffd3",
+            r: "00c328fafcbd79dd77850370c46325d987cb525569fb63c5d3bc53950e6d4c5f174e25a1ee9017b5d450606add152b534931d7d4e8455cc91f9b15bf05ec36e377fa",
+            s: "00617cce7cf5064806c467f678d3b4080d6f1cc50af26ca209417308281b68af282623eaa63e5b5c0723d8b8c37ff0777b1a20f8ccb1dccc43997f1ee0e44da4a67a",
+        },
+        Rfc6979TestVector {
+            // "test"
+            msg: "74657374",
+            k: "016200813020ec986863bedfc1b121f605c1215645018aea1a7b215a564de9eb1b38a67aa1128b80ce391c4fb71187654aaa3431027bfc7f395766ca988c964dc56d",
+            r: "013e99020abf5cee7525d16b69b229652ab6bdf2affcaef38773b4b7d08725f10cdb93482fdcc54edcee91eca4166b2a7c6265ef0ce2bd7051b7cef945babd47ee6d",
+            s: "01fbd0013c674aa79cb39849527916ce301c66ea7ce8b80682786ad60f98f7e78a19ca69eff5c57400e3b3a0ad66ce0978214d13baf4e9ac60752f7b155e2de4dce3",
+        },
+    ];
+    let sk = P521Scalar::from_hex("00fad06daa62ba3b25d2fb40133da757205de67f5bb0018fee8c86e1b68c7e75caa896eb32f1f47c70855836a6d16fcc1466f6d8fbec67db89ec0c08b0e996b83538");
+    let pk = public_key(sk).unwrap();
+    assert_eq!(
+        pk,
+        (
+            FieldElement::from_hex("01894550d0785932e00eaa23b694f213f8c3121f86dc97a04e5a7167db4e5bcd371123d46e45db6b5d5370a7f20fb633155d38ffa16d2bd761dcac474b9a2f5023a4"),
+            FieldElement::from_hex("00493101c962cd4d2fddf782285e64584139c2f91b47f87ff82354d6630f746a28a0db25741b5b34a828008b22acc23f924faafbd4d33f81ea66956dfeaa2bfdfcf5")
+        )
+    );
+    for kat in kat.iter() {
+        let msg = ByteSeq::from(kat.msg);
+        // The nonce is derived from two HMAC-SHA512 outputs truncated to
+        // 521 bits.
+        let k = rfc6979_nonce(sk, sha2::sha512(msg.clone()));
+        assert_eq!(k, P521Scalar::from_hex(kat.k));
+
+        let sig = sign(sk, msg.clone(), false).unwrap();
+        assert_eq!(
+            sig,
+            (P521Scalar::from_hex(kat.r), P521Scalar::from_hex(kat.s))
+        );
+        assert_eq!(
+            format!("{}{}", kat.r, kat.s),
+            signature_to_bytes(sig).to_hex()
+        );
+        assert_eq!(signature_from_bytes(signature_to_bytes(sig)).unwrap(), sig);
+        assert!(verify(pk, msg.clone(), sig).is_ok());
+        assert!(verify(pk, ByteSeq::from("00"), sig).is_err());
+    }
+
+    // DER encoding from OpenSSL with the long length form 0x81 0x87.
+    let sig = (
+        P521Scalar::from_hex(kat[0].r),
+        P521Scalar::from_hex(kat[0].s),
+    );
+    let der = signature_to_der(sig);
+    assert_eq!(
+        "308187024200c328fafcbd79dd77850370c46325d987cb525569fb63c5d3bc53950e6d4c5f174e25a1ee9017b5d450606add152b534931d7d4e8455cc91f9b15bf05ec36e377fa0241617cce7cf5064806c467f678d3b4080d6f1cc50af26ca209417308281b68af282623eaa63e5b5c0723d8b8c37ff0777b1a20f8ccb1dccc43997f1ee0e44da4a67a",
+        der.to_hex()
+    );
+    assert_eq!(signature_from_der(der).unwrap(), sig);
+
+    // Short signatures must not use the long length form.
+    assert!(signature_from_der(ByteSeq::from("308106020101020101")).is_err());
+    let small = (P521Scalar::from_literal(1), P521Scalar::from_literal(1));
+    assert_eq!("3006020101020101", signature_to_der(small).to_hex());
+}
+
+// NIST CAVP FIPS 186-3 ECDSA SigGen.txt, [P-521,SHA-512], COUNT = 0
+#[test]
+fn test_siggen_p521() {
+    use hacspecs::ecdsa::p521::*;
+    use hacspecs::p521::{self, FieldElement, P521Scalar};
+
+    let kat = SigGenTestVector {
+        msg: "9ecd500c60e701404922e58ab20cc002651fdee7cbc9336adda33e4c1088fab1964ecb7904dc6856865d6c8e15041ccf2d5ac302e99d346ff2f686531d25521678d4fd3f76bbf2c893d246cb4d7693792fe18172108146853103a51f824acc621cb7311d2463c3361ea707254f2b052bc22cb8012873dcbb95bf1a5cc53ab89f",
+        d: "00f749d32704bc533ca82cef0acf103d8f4fba67f08d2678e515ed7db886267ffaf02fab0080dca2359b72f574ccc29a0f218c8655c0cccf9fee6c5e567aa14cb926",
+        q_x: "0061387fd6b95914e885f912edfbb5fb274655027f216c4091ca83e19336740fd81aedfe047f51b42bdf68161121013e0d55b117a14e4303f926c8debb77a7fdaad1",
+        q_y: "00e7d0c75c38626e895ca21526b9f9fdf84dcecb93f2b233390550d2b1463b7ee3f58df7346435ff0434199583c97c665a97f12f706f2357da4b40288def888e59e6",
+        k: "003af5ab6caa29a6de86a5bab9aa83c3b16a17ffcd52b5c60c769be3053cdddeac60812d12fecf46cfe1f3db9ac9dcf881fcec3f0aa733d4ecbb83c7593e864c6df1",
+        r: "004de826ea704ad10bc0f7538af8a3843f284f55c8b946af9235af5af74f2b76e099e4bc72fd79d28a380f8d4b4c919ac290d248c37983ba05aea42e2dd79fdd33e8",
+        s: "0087488c859a96fea266ea13bf6d114c429b163be97a57559086edb64aed4a18594b46fb9efc7fd25d8b2de8f09ca0587f54bd287299f47b2ff124aac566e8ee3b43",
+    };
+    let d = P521Scalar::from_hex(kat.d);
+    let pk = public_key(d).unwrap();
+    assert_eq!(
+        pk,
+        (
+            FieldElement::from_hex(kat.q_x),
+            FieldElement::from_hex(kat.q_y)
+        )
+    );
+
+    // Sign with the nonce k of the test vector.
+    let msg = ByteSeq::from(kat.msg);
+    let k = P521Scalar::from_hex(kat.k);
+    let (x, _) = p521::point_mul_base(p521::Scalar::from_hex(kat.k)).unwrap();
+    let r = P521Scalar::from_byte_seq_be(x.to_byte_seq_be());
+    let e = P521Scalar::from_byte_seq_be(ByteSeq::from(sha2::sha512(msg.clone()).raw()));
+    let sig = (r, k.inv() * (e + r * d));
+    assert_eq!(
+        sig,
+        (P521Scalar::from_hex(kat.r), P521Scalar::from_hex(kat.s))
+    );
+    assert!(verify(pk, msg.clone(), sig).is_ok());
+
+    // Failure cases in the style of SigVer, derived from the vector above.
+    let mut other_msg = msg.clone();
+    other_msg[0] = other_msg[0] ^ U8(1);
+    assert!(verify(pk, other_msg, sig).is_err());
+    let one = P521Scalar::from_literal(1);
+    assert!(verify(pk, msg.clone(), (sig.0 + one, sig.1)).is_err());
+    assert!(verify(pk, msg.clone(), (sig.0, sig.1 + one)).is_err());
+    let other_pk = public_key(d + one).unwrap();
+    assert!(verify(other_pk, msg.clone(), sig).is_err());
+}
+
+struct RecoverableTestVector<'a> {
+    sk: &'a str,
+    pk_x: &'a str,
+    pk_y: &'a str,
+    msg: &'a str,
+    r: &'a str,
+    s: &'a str,
+    recid: u8,
+}
+
+// secp256k1 with SHA-256 and low-S normalization. The first two vectors are
+// the well-known "Satoshi Nakamoto" vectors, the other two use the key and
+// messages from RFC 6979 A.2.5. All were checked against OpenSSL.
+const SECP256K1_KAT: [RecoverableTestVector; 4] = [
+    RecoverableTestVector {
+        sk: "0000000000000000000000000000000000000000000000000000000000000001",
+        pk_x: "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+        pk_y: "483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8",
+        // "Satoshi Nakamoto"
+        msg: "5361746f736869204e616b616d6f746f",
+        r: "934b1ea10a4b3c1757e2b0c017d0b6143ce3c9a7e6a4a49860d7a6ab210ee3d8",
+        s: "2442ce9d2b916064108014783e923ec36b49743e2ffa1c4496f01a512aafd9e5",
+        recid: 1,
+    },
+    RecoverableTestVector {
+        sk: "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364140",
+        pk_x: "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+        pk_y: "b7c52588d95c3b9aa25b0403f1eef75702e84bb7597aabe663b82f6f04ef2777",
+        msg: "5361746f736869204e616b616d6f746f",
+        r: "fd567d121db66e382991534ada77a6bd3106f0a1098c231e47993447cd6af2d0",
+        s: "6b39cd0eb1bc8603e159ef5c20a5c8ad685a45b06ce9bebed3f153d10d93bed5",
+        recid: 0,
+    },
+    RecoverableTestVector {
+        sk: "c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721",
+        pk_x: "2c8c31fc9f990c6b55e3865a184a4ce50e09481f2eaeb3e60ec1cea13a6ae645",
+        pk_y: "64b95e4fdb6948c0386e189b006a29f686769b011704275e4459822dc3328085",
+        // "sample"
+        msg: "73616d706c65",
+        r: "432310e32cb80eb6503a26ce83cc165c783b870845fb8aad6d970889fcd7a6c8",
+        s: "530128b6b81c548874a6305d93ed071ca6e05074d85863d4056ce89b02bfab69",
+        recid: 0,
+    },
+    RecoverableTestVector {
+        sk: "c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721",
+        pk_x: "2c8c31fc9f990c6b55e3865a184a4ce50e09481f2eaeb3e60ec1cea13a6ae645",
+        pk_y: "64b95e4fdb6948c0386e189b006a29f686769b011704275e4459822dc3328085",
+        // "test"
+        msg: "74657374",
+        r: "f2adcea7139057be6409855ee96d008e0e5b5f532333ec17448e26a36f47bcb2",
+        s: "570c9d342779b40f513c0d75cbf93e3f3de7b01f6593f17bfc2ee87151414d64",
+        recid: 0,
+    },
+];
+
+#[test]
+fn test_secp256k1() {
+    use hacspecs::ecdsa::secp256k1::*;
+    use hacspecs::secp256k1::{FieldElement, Secp256k1Scalar};
+
+    for kat in SECP256K1_KAT.iter() {
+        let sk = Secp256k1Scalar::from_hex(kat.sk);
+        let pk = (
+            FieldElement::from_hex(kat.pk_x),
+            FieldElement::from_hex(kat.pk_y),
+        );
+        assert_eq!(public_key(sk).unwrap(), pk);
+        let msg = ByteSeq::from(kat.msg);
+        let expected = (
+            Secp256k1Scalar::from_hex(kat.r),
+            Secp256k1Scalar::from_hex(kat.s),
+        );
+        assert_eq!(sign(sk, msg.clone(), true).unwrap(), expected);
+        assert!(verify(pk, msg.clone(), expected).is_ok());
+
+        let (sig, recid) = sign_recoverable(sk, msg.clone(), true).unwrap();
+        assert_eq!(sig, expected);
+        assert_eq!(recid, kat.recid);
+        assert_eq!(recover_public_key(msg.clone(), sig, recid).unwrap(), pk);
+        // The other parity gives a different key.
+        assert_ne!(recover_public_key(msg.clone(), sig, recid ^ 1).unwrap(), pk);
+        // r + n is not a valid x-coordinate for these signatures.
+        assert!(recover_public_key(msg.clone(), sig, recid | 2).is_err());
+        assert!(recover_public_key(msg.clone(), sig, 4).is_err());
+
+        // Without low-S the recovery id refers to the original R.
+        let (high, high_recid) = sign_recoverable(sk, msg.clone(), false).unwrap();
+        assert_eq!(recover_public_key(msg, high, high_recid).unwrap(), pk);
+    }
+}
@@ -0,0 +1,235 @@
+extern crate hacspec;
+use hacspec::prelude::*;
+
+extern crate hacspecs;
+use hacspecs::curve25519::FieldElement;
+use hacspecs::ed25519::*;
+use hacspecs::sha2::sha512;
+
+struct Ed25519TestVector<'a> {
+    sk: &'a str,
+    pk: &'a str,
+    msg: &'a str,
+    sig: &'a str,
+}
+
+// https://tools.ietf.org/html/rfc8032#section-7.1
+const KAT: [Ed25519TestVector; 4] = [
+    Ed25519TestVector {
+        sk: "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60",
+        pk: "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a",
+        msg: "",
+        sig: "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b",
+    },
+    Ed25519TestVector {
+        sk: "4ccd089b28ff96da9db6c346ec114e0f5b8a319f35aba624da8cf6ed4fb8a6fb",
+        pk: "3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c",
+        msg: "72",
+        sig: "92a009a9f0d4cab8720e820b5f642540a2b27b5416503f8fb3762223ebdb69da085ac1e43e15996e458f3613d0f11d8c387b2eaeb4302aeeb00d291612bb0c00",
+    },
+    Ed25519TestVector {
+        sk: "c5aa8df43f9f837bedb7442f31dcb7b166d38535076f094b85ce3a2e0b4458f7",
+        pk: "fc51cd8e6218a1a38da47ed00230f0580816ed13ba3303ac5deb911548908025",
+        msg: "af82",
+        sig: "6291d657deec24024827e69c3abe01a30ce548a284743a445e3680d7db5ac3ac18ff9b538d16f290ae67f760984dc6594a7c15e9716ed28dc027beceea1ec40a",
+    },
+    Ed25519TestVector {
+        sk: "833fe62409237b9d62ec77587520911e9a759cec1d19755b7da901b96dca3d42",
+        pk: "ec172b93ad5e563bf4932c70e1245034c35467ef2efd4d64ebf819683467e2bf",
+        msg: "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f",
+        sig: "dc2a4459e7369633a52b1bf277839a00201009a3efbf3ecb69bea2186c26b58909351fc9ac90b3ecfdfbc7c66431e0303dca179c138ac17ad9bef1177331a704",
+    },
+];
+
+#[test]
+fn test_kat() {
+    for kat in KAT.iter() {
+        let sk = SecretKey::from(kat.sk);
+        let pk = secret_to_public(sk);
+        assert_eq!(kat.pk, pk.to_hex());
+
+        let sig = sign(sk, ByteSeq::from(kat.msg));
+        assert_eq!(kat.sig, sig.to_hex());
+        assert!(verify(pk, ByteSeq::from(kat.msg), sig).is_ok());
+        assert!(verify_cofactorless(pk, ByteSeq::from(kat.msg), sig).is_ok());
+    }
+}
+
+#[test]
+fn test_invalid() {
+    let sk = SecretKey::random();
+    let pk = secret_to_public(sk);
+    let msg = ByteSeq::random(40);
+    let sig = sign(sk, msg.clone());
+    assert!(verify(pk, msg.clone(), sig).is_ok());
+
+    // Wrong message
+    assert!(verify(pk, ByteSeq::random(40), sig).is_err());
+
+    // S + l is not reduced
+    let s = Scalar::from_byte_seq_le(SerializedScalar::from_sub(sig, 32..64));
+    let l =
+        ScalarCanvas::from_hex("1000000000000000000000000000000014def9dea2f79cd65812631a5cf5d3ed");
+    let s_l = ScalarCanvas::from_byte_seq_le(s.to_byte_seq_le()) + l;
+    let mut sig_l = sig;
+    sig_l = sig_l.update(32, SerializedScalar::copy_pad(s_l.to_byte_seq_le()));
+    assert!(verify(pk, msg.clone(), sig_l).is_err());
+
+    // y = p is not a canonical encoding
+    let non_canonical =
+        CompressedPoint::from("edffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f");
+    assert!(point_decompress(non_canonical).is_err());
+    assert!(verify(non_canonical, msg, sig).is_err());
+}
+
+#[test]
+fn test_compress_decompress() {
+    let b = base_point();
+    let b_enc = point_compress(b);
+    assert_eq!(
+        "5866666666666666666666666666666666666666666666666666666666666666",
+        b_enc.to_hex()
+    );
+    assert!(point_eq(b, point_decompress(b_enc).unwrap()));
+    let p = point_mul_base(Scalar::from_literal(1234));
+    assert!(point_eq(p, point_decompress(point_compress(p)).unwrap()));
+    assert!(point_eq(point_double(p), point_add(p, p)));
+}
+
+#[test]
+fn test_point_mul() {
+    let b = base_point();
+    assert!(point_eq(
+        point_identity(),
+        point_mul_base(Scalar::from_literal(0))
+    ));
+    assert!(point_eq(b, point_mul_base(Scalar::from_literal(1))));
+    let p = point_mul_base(Scalar::from_literal(5));
+    let sum = point_add(point_double(point_double(b)), b);
+    assert!(point_eq(p, sum));
+    // l - 1 sets the highest bits of the scalar.
+    let l_minus_1 = Scalar::from_literal(0) - Scalar::from_literal(1);
+    assert!(point_eq(point_neg(b), point_mul_base(l_minus_1)));
+}
+
+#[test]
+fn test_cofactored_verification() {
+    // A signature with a nonce point R + T where T = (0, -1) has order 2.
+    // The cofactored equation accepts it, the cofactorless one doesn't.
+    let sk = SecretKey::random();
+    let pk = secret_to_public(sk);
+    let msg = ByteSeq::random(40);
+    let (s, _) = secret_expand(sk);
+    let r = Scalar::from_literal(1234);
+    let t = (
+        FieldElement::from_literal(0),
+        fe_neg(FieldElement::from_literal(1)),
+        FieldElement::from_literal(1),
+        FieldElement::from_literal(0),
+    );
+    let r_enc = point_compress(point_add(point_mul_base(r), t));
+
+    let mut k_in = ByteSeq::new(64 + msg.len());
+    k_in = k_in.update(0, r_enc);
+    k_in = k_in.update(32, pk);
+    k_in = k_in.update(64, msg.clone());
+    let k = scalar_from_hash(k_in);
+
+    let mut sig = Signature::new();
+    sig = sig.update(0, r_enc);
+    sig = sig.update(32, scalar_encode(r + k * s));
+    assert!(verify(pk, msg.clone(), sig).is_ok());
+    assert!(verify_cofactorless(pk, msg, sig).is_err());
+}
+
+struct Ed25519CtxTestVector<'a> {
+    sk: &'a str,
+    pk: &'a str,
+    msg: &'a str,
+    ctx: &'a str,
+    sig: &'a str,
+}
+
+// https://tools.ietf.org/html/rfc8032#section-7.2
+const CTX_KAT: [Ed25519CtxTestVector; 4] = [
+    Ed25519CtxTestVector {
+        sk: "0305334e381af78f141cb666f6199f57bc3495335a256a95bd2a55bf546663f6",
+        pk: "dfc9425e4f968f7f0c29f0259cf5f9aed6851c2bb4ad8bfb860cfee0ab248292",
+        msg: "f726936d19c800494e3fdaff20b276a8",
+        ctx: "666f6f",
+        sig: "55a4cc2f70a54e04288c5f4cd1e45a7bb520b36292911876cada7323198dd87a8b36950b95130022907a7fb7c4e9b2d5f6cca685a587b4b21f4b888e4e7edb0d",
+    },
+    Ed25519CtxTestVector {
+        sk: "0305334e381af78f141cb666f6199f57bc3495335a256a95bd2a55bf546663f6",
+        pk: "dfc9425e4f968f7f0c29f0259cf5f9aed6851c2bb4ad8bfb860cfee0ab248292",
+        msg: "f726936d19c800494e3fdaff20b276a8",
+        ctx: "626172",
+        sig: "fc60d5872fc46b3aa69f8b5b4351d5808f92bcc044606db097abab6dbcb1aee3216c48e8b3b66431b5b186d1d28f8ee15a5ca2df6668346291c2043d4eb3e90d",
+    },
+    Ed25519CtxTestVector {
+        sk: "0305334e381af78f141cb666f6199f57bc3495335a256a95bd2a55bf546663f6",
+        pk: "dfc9425e4f968f7f0c29f0259cf5f9aed6851c2bb4ad8bfb860cfee0ab248292",
+        msg: "508e9e6882b979fea900f62adceaca35",
+        ctx: "666f6f",
+        sig: "8b70c1cc8310e1de20ac53ce28ae6e7207f33c3295e03bb5c0732a1d20dc64908922a8b052cf99b7c4fe107a5abb5b2c4085ae75890d02df26269d8945f84b0b",
+    },
+    Ed25519CtxTestVector {
+        sk: "ab9c2853ce297ddab85c993b3ae14bcad39b2c682beabc27d6d4eb20711d6560",
+        pk: "0f1d1274943b91415889152e893d80e93275a1fc0b65fd71b4b0dda10ad7d772",
+        msg: "f726936d19c800494e3fdaff20b276a8",
+        ctx: "666f6f",
+        sig: "21655b5f1aa965996b3f97b3c849eafba922a0a62992f73b3d1b73106a84ad85e9b86a7b6005ea868337ff2d20a7f5fbd4cd10b0be49a68da2b2e0dc0ad8960f",
+    },
+];
+
+#[test]
+fn test_ctx_kat() {
+    for kat in CTX_KAT.iter() {
+        let sk = SecretKey::from(kat.sk);
+        let pk = secret_to_public(sk);
+        assert_eq!(kat.pk, pk.to_hex());
+
+        let msg = ByteSeq::from(kat.msg);
+        let ctx = ByteSeq::from(kat.ctx);
+        let sig = sign_ctx(sk, ctx.clone(), msg.clone()).unwrap();
+        assert_eq!(kat.sig, sig.to_hex());
+        assert!(verify_ctx(pk, ctx, msg.clone(), sig).is_ok());
+        // The signature is bound to the context.
+        assert!(verify_ctx(pk, ByteSeq::from("71757578"), msg.clone(), sig).is_err());
+        assert!(verify(pk, msg, sig).is_err());
+    }
+}
+
+// https://tools.ietf.org/html/rfc8032#section-7.3
+#[test]
+fn test_ph_kat() {
+    let sk = SecretKey::from("833fe62409237b9d62ec77587520911e9a759cec1d19755b7da901b96dca3d42");
+    let pk = secret_to_public(sk);
+    assert_eq!(
+        "ec172b93ad5e563bf4932c70e1245034c35467ef2efd4d64ebf819683467e2bf",
+        pk.to_hex()
+    );
+    let msg = ByteSeq::from("616263");
+    let expected = "98a70222f0b8121aa9d30f813d683f809e462b469c7ff87639499bb94e6dae4131f85042463c2a355a2003d062adf5aaa10b8c61e636062aaad11c2a26083406";
+
+    let sig = sign_ph(sk, ByteSeq::new(0), msg.clone()).unwrap();
+    assert_eq!(expected, sig.to_hex());
+    assert!(verify_ph(pk, ByteSeq::new(0), msg.clone(), sig).is_ok());
+
+    let ph = sha512(msg.clone());
+    let sig = sign_prehashed(sk, ByteSeq::new(0), ph).unwrap();
+    assert_eq!(expected, sig.to_hex());
+    assert!(verify_prehashed(pk, ByteSeq::new(0), ph, sig).is_ok());
+    assert!(verify(pk, msg, sig).is_err());
+}
+
+#[test]
+fn test_ctx_length() {
+    let sk = SecretKey::random();
+    let msg = ByteSeq::random(10);
+    assert!(sign_ctx(sk, ByteSeq::new(0), msg.clone()).is_err());
+    assert!(sign_ctx(sk, ByteSeq::random(255), msg.clone()).is_ok());
+    assert!(sign_ctx(sk, ByteSeq::random(256), msg.clone()).is_err());
+    assert!(sign_ph(sk, ByteSeq::random(255), msg.clone()).is_ok());
+    assert!(sign_ph(sk, ByteSeq::random(256), msg).is_err());
+}
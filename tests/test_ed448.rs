@@ -0,0 +1,119 @@
+extern crate hacspec;
+use hacspec::prelude::*;
+
+extern crate hacspecs;
+use hacspecs::ed448::*;
+use hacspecs::edwards448::*;
+
+struct Ed448TestVector<'a> {
+    sk: &'a str,
+    pk: &'a str,
+    msg: &'a str,
+    ctx: &'a str,
+    sig: &'a str,
+}
+
+// https://tools.ietf.org/html/rfc8032#section-7.4
+const KAT: [Ed448TestVector; 2] = [
+    Ed448TestVector {
+        sk: "6c82a562cb808d10d632be89c8513ebf6c929f34ddfa8c9f63c9960ef6e348a3528c8a3fcc2f044e39a3fc5b94492f8f032e7549a20098f95b",
+        pk: "5fd7449b59b461fd2ce787ec616ad46a1da1342485a70e1f8a0ea75d80e96778edf124769b46c7061bd6783df1e50f6cd1fa1abeafe8256180",
+        msg: "",
+        ctx: "",
+        sig: "533a37f6bbe457251f023c0d88f976ae2dfb504a843e34d2074fd823d41a591f2b233f034f628281f2fd7a22ddd47d7828c59bd0a21bfd3980ff0d2028d4b18a9df63e006c5d1c2d345b925d8dc00b4104852db99ac5c7cdda8530a113a0f4dbb61149f05a7363268c71d95808ff2e652600",
+    },
+    Ed448TestVector {
+        sk: "c4eab05d357007c632f3dbb48489924d552b08fe0c353a0d4a1f00acda2c463afbea67c5e8d2877c5e3bc397a659949ef8021e954e0a12274e",
+        pk: "43ba28f430cdff456ae531545f7ecd0ac834a55d9358c0372bfa0c6c6798c0866aea01eb00742802b8438ea4cb82169c235160627b4c3a9480",
+        msg: "03",
+        ctx: "666f6f",
+        sig: "d4f8f6131770dd46f40867d6fd5d5055de43541f8c5e35abbcd001b32a89f7d2151f7647f11d8ca2ae279fb842d607217fce6e042f6815ea000c85741de5c8da1144a6a1aba7f96de42505d7a7298524fda538fccbbb754f578c1cad10d54d0d5428407e85dcbc98a49155c13764e66c3c00",
+    },
+];
+
+#[test]
+fn test_kat() {
+    for kat in KAT.iter() {
+        let sk = SecretKey::from(kat.sk);
+        let pk = secret_to_public(sk);
+        assert_eq!(kat.pk, pk.to_hex());
+
+        let msg = ByteSeq::from(kat.msg);
+        let ctx = ByteSeq::from(kat.ctx);
+        let sig = sign(sk, ctx.clone(), msg.clone()).unwrap();
+        assert_eq!(kat.sig, sig.to_hex());
+        assert!(verify(pk, ctx, msg.clone(), sig).is_ok());
+        assert!(verify(pk, ByteSeq::from("626172"), msg, sig).is_err());
+    }
+}
+
+// https://tools.ietf.org/html/rfc8032#section-7.5
+const PH_KAT: [Ed448TestVector; 2] = [
+    Ed448TestVector {
+        sk: "833fe62409237b9d62ec77587520911e9a759cec1d19755b7da901b96dca3d42ef7822e0d5104127dc05d6dbefde69e3ab2cec7c867c6e2c49",
+        pk: "259b71c19f83ef77a7abd26524cbdb3161b590a48f7d17de3ee0ba9c52beb743c09428a131d6b1b57303d90d8132c276d5ed3d5d01c0f53880",
+        msg: "616263",
+        ctx: "",
+        sig: "822f6901f7480f3d5f562c592994d9693602875614483256505600bbc281ae381f54d6bce2ea911574932f52a4e6cadd78769375ec3ffd1b801a0d9b3f4030cd433964b6457ea39476511214f97469b57dd32dbc560a9a94d00bff07620464a3ad203df7dc7ce360c3cd3696d9d9fab90f00",
+    },
+    Ed448TestVector {
+        sk: "833fe62409237b9d62ec77587520911e9a759cec1d19755b7da901b96dca3d42ef7822e0d5104127dc05d6dbefde69e3ab2cec7c867c6e2c49",
+        pk: "259b71c19f83ef77a7abd26524cbdb3161b590a48f7d17de3ee0ba9c52beb743c09428a131d6b1b57303d90d8132c276d5ed3d5d01c0f53880",
+        msg: "616263",
+        ctx: "666f6f",
+        sig: "c32299d46ec8ff02b54540982814dce9a05812f81962b649d528095916a2aa481065b1580423ef927ecf0af5888f90da0f6a9a85ad5dc3f280d91224ba9911a3653d00e484e2ce232521481c8658df304bb7745a73514cdb9bf3e15784ab71284f8d0704a608c54a6b62d97beb511d132100",
+    },
+];
+
+#[test]
+fn test_ph_kat() {
+    for kat in PH_KAT.iter() {
+        let sk = SecretKey::from(kat.sk);
+        let pk = secret_to_public(sk);
+        assert_eq!(kat.pk, pk.to_hex());
+
+        let msg = ByteSeq::from(kat.msg);
+        let ctx = ByteSeq::from(kat.ctx);
+        let sig = sign_ph(sk, ctx.clone(), msg.clone()).unwrap();
+        assert_eq!(kat.sig, sig.to_hex());
+        assert!(verify_ph(pk, ctx.clone(), msg.clone(), sig).is_ok());
+        assert!(verify_prehashed(pk, ctx.clone(), prehash(msg.clone()), sig).is_ok());
+        // Ed448 and Ed448ph signatures are not interchangeable.
+        assert!(verify(pk, ctx, msg, sig).is_err());
+    }
+}
+
+#[test]
+fn test_invalid() {
+    let sk = SecretKey::random();
+    let pk = secret_to_public(sk);
+    let msg = ByteSeq::random(40);
+    let ctx = ByteSeq::new(0);
+    let sig = sign(sk, ctx.clone(), msg.clone()).unwrap();
+    assert!(verify(pk, ctx.clone(), msg.clone(), sig).is_ok());
+
+    // Wrong message
+    assert!(verify(pk, ctx.clone(), ByteSeq::random(40), sig).is_err());
+
+    // Unused bits in the last byte of R must be zero
+    let mut sig_r = sig;
+    sig_r[56] |= U8(0x01);
+    assert!(verify(pk, ctx.clone(), msg.clone(), sig_r).is_err());
+
+    // The context is limited to 255 bytes
+    assert!(sign(sk, ByteSeq::random(256), msg.clone()).is_err());
+    assert!(verify(pk, ByteSeq::random(256), msg, sig).is_err());
+}
+
+#[test]
+fn test_points() {
+    let b = base_point();
+    assert!(point_eq(point_add(b, b), point_double(b)));
+    assert!(point_eq(point_add(b, point_neg(b)), point_identity()));
+    assert!(point_eq(point_add(point_identity(), b), b));
+
+    let s = Scalar::from_literal(1234567);
+    let p = point_mul_base(s);
+    let q = point_decompress(point_compress(p)).unwrap();
+    assert!(point_eq(p, q));
+}
@@ -0,0 +1,369 @@
+extern crate hacspec;
+use hacspec::prelude::*;
+
+extern crate hacspecs;
+use hacspecs::curve25519::FieldElement;
+use hacspecs::ed25519;
+use hacspecs::hash_to_curve::*;
+use hacspecs::hash_to_curve25519::*;
+use hacspecs::hash_to_curve_p256::{self, *};
+use hacspecs::p256;
+
+// https://www.rfc-editor.org/rfc/rfc9380#appendix-K.3
+const EXPAND_KAT: [(&str, usize, &str); 4] = [
+    (
+        "",
+        32,
+        "6b9a7312411d92f921c6f68ca0b6380730a1a4d982c507211a90964c394179ba",
+    ),
+    (
+        "616263",
+        32,
+        "0da749f12fbe5483eb066a5f595055679b976e93abe9be6f0f6318bce7aca8dc",
+    ),
+    (
+        "713132385f7171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171",
+        32,
+        "7336234ee9983902440f6bc35b348352013becd88938d2afec44311caf8356b3",
+    ),
+    (
+        "",
+        128,
+        "41b037d1734a5f8df225dd8c7de38f851efdb45c372887be655212d07251b921b052b62eaed99b46f72f2ef4cc96bfaf254ebbbec091e1a3b9e4fb5e5b619d2e0c5414800a1d882b62bb5cd1778f098b8eb6cb399d5d9d18f5d5842cf5d13d7eb00a7cff859b605da678b318bd0e65ebff70bec88c753b159a805d2c89c55961",
+    ),
+];
+
+// https://www.rfc-editor.org/rfc/rfc9380#appendix-K.1
+const EXPAND_SHA256_KAT: [(&str, usize, &str); 4] = [
+    (
+        "",
+        32,
+        "68a985b87eb6b46952128911f2a4412bbc302a9d759667f87f7a21d803f07235",
+    ),
+    (
+        "616263",
+        32,
+        "d8ccab23b5985ccea865c6c97b6e5b8350e794e603b4b97902f53a8a0d605615",
+    ),
+    (
+        "713132385f7171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171",
+        32,
+        "b23a1d2b4d97b2ef7785562a7e8bac7eed54ed6e97e29aa51bfe3f12ddad1ff9",
+    ),
+    (
+        "",
+        128,
+        "af84c27ccfd45d41914fdff5df25293e221afc53d8ad2ac06d5e3e29485dadbee0d121587713a3e0dd4d5e69e93eb7cd4f5df4cd103e188cf60cb02edc3edf18eda8576c412b18ffb658e3dd6ec849469b979d444cf7b26911a08e63cf31f9dcc541708d3491184472c2c29bb749d4286b004ceb5ee6b9a7fa5b646c993f0ced",
+    ),
+];
+
+// https://www.rfc-editor.org/rfc/rfc9380#appendix-J.1.1
+const P256_RO_KAT: [(&str, &str, &str); 4] = [
+    (
+        "",
+        "2c15230b26dbc6fc9a37051158c95b79656e17a1a920b11394ca91c44247d3e4",
+        "8a7a74985cc5c776cdfe4b1f19884970453912e9d31528c060be9ab5c43e8415",
+    ),
+    (
+        "616263",
+        "0bb8b87485551aa43ed54f009230450b492fead5f1cc91658775dac4a3388a0f",
+        "5c41b3d0731a27a7b14bc0bf0ccded2d8751f83493404c84a88e71ffd424212e",
+    ),
+    (
+        "61626364656630313233343536373839",
+        "65038ac8f2b1def042a5df0b33b1f4eca6bff7cb0f9c6c1526811864e544ed80",
+        "cad44d40a656e7aff4002a8de287abc8ae0482b5ae825822bb870d6df9b56ca3",
+    ),
+    (
+        "713132385f7171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171",
+        "4be61ee205094282ba8a2042bcb48d88dfbb609301c49aa8b078533dc65a0b5d",
+        "98f8df449a072c4721d241a3b1236d3caccba603f916ca680f4539d2bfb3c29e",
+    ),
+];
+
+// https://www.rfc-editor.org/rfc/rfc9380#appendix-J.1.2
+const P256_NU_KAT: [(&str, &str, &str); 4] = [
+    (
+        "",
+        "f871caad25ea3b59c16cf87c1894902f7e7b2c822c3d3f73596c5ace8ddd14d1",
+        "87b9ae23335bee057b99bac1e68588b18b5691af476234b8971bc4f011ddc99b",
+    ),
+    (
+        "616263",
+        "fc3f5d734e8dce41ddac49f47dd2b8a57257522a865c124ed02b92b5237befa4",
+        "fe4d197ecf5a62645b9690599e1d80e82c500b22ac705a0b421fac7b47157866",
+    ),
+    (
+        "61626364656630313233343536373839",
+        "f164c6674a02207e414c257ce759d35eddc7f55be6d7f415e2cc177e5d8faa84",
+        "3aa274881d30db70485368c0467e97da0e73c18c1d00f34775d012b6fcee7f97",
+    ),
+    (
+        "713132385f7171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171",
+        "324532006312be4f162614076460315f7a54a6f85544da773dc659aca0311853",
+        "8d8197374bcd52de2acfefc8a54fe2c8d8bebd2a39f16be9b710e4b1af6ef883",
+    ),
+];
+
+// https://www.rfc-editor.org/rfc/rfc9380#appendix-J.4.1
+const CURVE25519_RO_KAT: [(&str, &str, &str); 4] = [
+    (
+        "",
+        "2de3780abb67e861289f5749d16d3e217ffa722192d16bbd9d1bfb9d112b98c0",
+        "3b5dc2a498941a1033d176567d457845637554a2fe7a3507d21abd1c1bd6e878",
+    ),
+    (
+        "616263",
+        "2b4419f1f2d48f5872de692b0aca72cc7b0a60915dd70bde432e826b6abc526d",
+        "1b8235f255a268f0a6fa8763e97eb3d22d149343d495da1160eff9703f2d07dd",
+    ),
+    (
+        "61626364656630313233343536373839",
+        "68ca1ea5a6acf4e9956daa101709b1eee6c1bb0df1de3b90d4602382a104c036",
+        "2a375b656207123d10766e68b938b1812a4a6625ff83cb8d5e86f58a4be08353",
+    ),
+    (
+        "713132385f7171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171",
+        "096e9c8bae6c06b554c1ee69383bb0e82267e064236b3a30608d4ed20b73ac5a",
+        "1eb5a62612cafb32b16c3329794645b5b948d9f8ffe501d4e26b073fef6de355",
+    ),
+];
+
+// https://www.rfc-editor.org/rfc/rfc9380#appendix-J.4.2
+const CURVE25519_NU_KAT: [(&str, &str, &str); 4] = [
+    (
+        "",
+        "1bb913f0c9daefa0b3375378ffa534bda5526c97391952a7789eb976edfe4d08",
+        "4548368f4f983243e747b62a600840ae7c1dab5c723991f85d3a9768479f3ec4",
+    ),
+    (
+        "616263",
+        "7c22950b7d900fa866334262fcaea47a441a578df43b894b4625c9b450f9a026",
+        "5547bc00e4c09685dcbc6cb6765288b386d8bdcb595fa5a6e3969e08097f0541",
+    ),
+    (
+        "61626364656630313233343536373839",
+        "31ad08a8b0deeb2a4d8b0206ca25f567ab4e042746f792f4b7973f3ae2096c52",
+        "405070c28e78b4fa269427c82827261991b9718bd6c6e95d627d701a53c30db1",
+    ),
+    (
+        "713132385f7171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171",
+        "027877759d155b1997d0d84683a313eb78bdb493271d935b622900459d52ceaa",
+        "54d691731a53baa30707f4a87121d5169fb5d587d70fb0292b5830dedbec4c18",
+    ),
+];
+
+// https://www.rfc-editor.org/rfc/rfc9380#appendix-J.5.1
+const EDWARDS25519_RO_KAT: [(&str, &str, &str); 4] = [
+    (
+        "",
+        "3c3da6925a3c3c268448dcabb47ccde5439559d9599646a8260e47b1e4822fc6",
+        "09a6c8561a0b22bef63124c588ce4c62ea83a3c899763af26d795302e115dc21",
+    ),
+    (
+        "616263",
+        "608040b42285cc0d72cbb3985c6b04c935370c7361f4b7fbdb1ae7f8c1a8ecad",
+        "1a8395b88338f22e435bbd301183e7f20a5f9de643f11882fb237f88268a5531",
+    ),
+    (
+        "61626364656630313233343536373839",
+        "6d7fabf47a2dc03fe7d47f7dddd21082c5fb8f86743cd020f3fb147d57161472",
+        "53060a3d140e7fbcda641ed3cf42c88a75411e648a1add71217f70ea8ec561a6",
+    ),
+    (
+        "713132385f7171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171",
+        "5fb0b92acedd16f3bcb0ef83f5c7b7a9466b5f1e0d8d217421878ea3686f8524",
+        "2eca15e355fcfa39d2982f67ddb0eea138e2994f5956ed37b7f72eea5e89d2f7",
+    ),
+];
+
+// https://www.rfc-editor.org/rfc/rfc9380#appendix-J.5.2
+const EDWARDS25519_NU_KAT: [(&str, &str, &str); 4] = [
+    (
+        "",
+        "1ff2b70ecf862799e11b7ae744e3489aa058ce805dd323a936375a84695e76da",
+        "222e314d04a4d5725e9f2aff9fb2a6b69ef375a1214eb19021ceab2d687f0f9b",
+    ),
+    (
+        "616263",
+        "5f13cc69c891d86927eb37bd4afc6672360007c63f68a33ab423a3aa040fd2a8",
+        "67732d50f9a26f73111dd1ed5dba225614e538599db58ba30aaea1f5c827fa42",
+    ),
+    (
+        "61626364656630313233343536373839",
+        "1dd2fefce934ecfd7aae6ec998de088d7dd03316aa1847198aecf699ba6613f1",
+        "2f8a6c24dd1adde73909cada6a4a137577b0f179d336685c4a955a0a8e1a86fb",
+    ),
+    (
+        "713132385f7171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171",
+        "35fbdc5143e8a97afd3096f2b843e07df72e15bfca2eaf6879bf97c5d3362f73",
+        "2af6ff6ef5ebba128b0774f4296cb4c2279a074658b083b8dcca91f57a603450",
+    ),
+];
+
+#[test]
+fn test_expand_message_xmd_sha512() {
+    // "QUUX-V01-CS02-with-expander-SHA512-256"
+    let dst = ByteSeq::from(
+        "515555582d5630312d435330322d776974682d657870616e6465722d5348413531322d323536",
+    );
+    for (msg, len, expected) in EXPAND_KAT.iter() {
+        let out = expand_message_xmd_sha512(ByteSeq::from(*msg), dst.clone(), *len).unwrap();
+        assert_eq!(*expected, out.to_hex());
+    }
+    assert!(expand_message_xmd_sha512(ByteSeq::new(0), dst.clone(), 255 * 64).is_ok());
+    assert!(expand_message_xmd_sha512(ByteSeq::new(0), dst, 255 * 64 + 1).is_err());
+    assert!(expand_message_xmd_sha512(ByteSeq::new(0), ByteSeq::new(256), 32).is_err());
+}
+
+#[test]
+fn test_expand_message_xmd_sha256() {
+    // "QUUX-V01-CS02-with-expander-SHA256-128"
+    let dst = ByteSeq::from(
+        "515555582d5630312d435330322d776974682d657870616e6465722d5348413235362d313238",
+    );
+    for (msg, len, expected) in EXPAND_SHA256_KAT.iter() {
+        let out = expand_message_xmd_sha256(ByteSeq::from(*msg), dst.clone(), *len).unwrap();
+        assert_eq!(*expected, out.to_hex());
+    }
+    assert!(expand_message_xmd_sha256(ByteSeq::new(0), dst.clone(), 255 * 32).is_ok());
+    assert!(expand_message_xmd_sha256(ByteSeq::new(0), dst, 255 * 32 + 1).is_err());
+}
+
+fn check_montgomery(
+    kat: &[(&str, &str, &str)],
+    dst: &str,
+    h2c: fn(ByteSeq, ByteSeq) -> Result<MontgomeryPoint, String>,
+) {
+    for (msg, x, y) in kat.iter() {
+        let (u, v) = h2c(ByteSeq::from(*msg), ByteSeq::from(dst)).unwrap();
+        assert!(u == FieldElement::from_hex(x));
+        assert!(v == FieldElement::from_hex(y));
+    }
+}
+
+fn check_edwards(
+    kat: &[(&str, &str, &str)],
+    dst: &str,
+    h2c: fn(ByteSeq, ByteSeq) -> Result<ed25519::Point, String>,
+) {
+    for (msg, x, y) in kat.iter() {
+        let p = h2c(ByteSeq::from(*msg), ByteSeq::from(dst)).unwrap();
+        let one = FieldElement::from_literal(1);
+        let expected = (
+            FieldElement::from_hex(x),
+            FieldElement::from_hex(y),
+            one,
+            FieldElement::from_hex(x) * FieldElement::from_hex(y),
+        );
+        assert!(ed25519::point_eq(p, expected));
+    }
+}
+
+#[test]
+fn test_curve25519() {
+    check_montgomery(
+        &CURVE25519_RO_KAT,
+        // "QUUX-V01-CS02-with-curve25519_XMD:SHA-512_ELL2_RO_"
+        "515555582d5630312d435330322d776974682d637572766532353531395f584d443a5348412d3531325f454c4c325f524f5f",
+        curve25519_hash_to_curve,
+    );
+    check_montgomery(
+        &CURVE25519_NU_KAT,
+        // "QUUX-V01-CS02-with-curve25519_XMD:SHA-512_ELL2_NU_"
+        "515555582d5630312d435330322d776974682d637572766532353531395f584d443a5348412d3531325f454c4c325f4e555f",
+        curve25519_encode_to_curve,
+    );
+}
+
+#[test]
+fn test_edwards25519() {
+    check_edwards(
+        &EDWARDS25519_RO_KAT,
+        // "QUUX-V01-CS02-with-edwards25519_XMD:SHA-512_ELL2_RO_"
+        "515555582d5630312d435330322d776974682d6564776172647332353531395f584d443a5348412d3531325f454c4c325f524f5f",
+        edwards25519_hash_to_curve,
+    );
+    check_edwards(
+        &EDWARDS25519_NU_KAT,
+        // "QUUX-V01-CS02-with-edwards25519_XMD:SHA-512_ELL2_NU_"
+        "515555582d5630312d435330322d776974682d6564776172647332353531395f584d443a5348412d3531325f454c4c325f4e555f",
+        edwards25519_encode_to_curve,
+    );
+}
+
+#[test]
+fn test_rational_map() {
+    let p = ed25519::point_mul_base(ed25519::Scalar::from_literal(42));
+    let q = montgomery_to_edwards(edwards_to_montgomery(p));
+    assert!(ed25519::point_eq(p, q));
+}
+
+fn check_p256(
+    kat: &[(&str, &str, &str)],
+    dst: &str,
+    h2c: fn(ByteSeq, ByteSeq) -> Result<p256::Affine, String>,
+) {
+    for (msg, x, y) in kat.iter() {
+        let p = h2c(ByteSeq::from(*msg), ByteSeq::from(dst)).unwrap();
+        assert_eq!(
+            p,
+            (
+                p256::FieldElement::from_hex(x),
+                p256::FieldElement::from_hex(y)
+            )
+        );
+        assert!(p256::is_point_on_curve(p));
+    }
+}
+
+#[test]
+fn test_p256() {
+    check_p256(
+        &P256_RO_KAT,
+        // "QUUX-V01-CS02-with-P256_XMD:SHA-256_SSWU_RO_"
+        "515555582d5630312d435330322d776974682d503235365f584d443a5348412d3235365f535357555f524f5f",
+        p256_hash_to_curve,
+    );
+    check_p256(
+        &P256_NU_KAT,
+        // "QUUX-V01-CS02-with-P256_XMD:SHA-256_SSWU_NU_"
+        "515555582d5630312d435330322d776974682d503235365f584d443a5348412d3235365f535357555f4e555f",
+        p256_encode_to_curve,
+    );
+}
+
+#[test]
+fn test_p256_map_to_curve() {
+    // u0 and Q0 of the first P256_XMD:SHA-256_SSWU_RO_ vector
+    let dst = ByteSeq::from(
+        "515555582d5630312d435330322d776974682d503235365f584d443a5348412d3235365f535357555f524f5f",
+    );
+    let (u0, u1) = hash_to_curve_p256::hash_to_field(ByteSeq::new(0), dst).unwrap();
+    assert_eq!(
+        u0,
+        p256::FieldElement::from_hex(
+            "ad5342c66a6dd0ff080df1da0ea1c04b96e0330dd89406465eeba11582515009"
+        )
+    );
+    assert_eq!(
+        u1,
+        p256::FieldElement::from_hex(
+            "8c0f1d43204bd6f6ea70ae8013070a1518b43873bcd850aafa0a9e220e2eea5a"
+        )
+    );
+    assert_eq!(
+        map_to_curve_simple_swu(u0),
+        (
+            p256::FieldElement::from_hex(
+                "ab640a12220d3ff283510ff3f4b1953d09fad35795140b1c5d64f313967934d5"
+            ),
+            p256::FieldElement::from_hex(
+                "dccb558863804a881d4fff3455716c836cef230e5209594ddd33d85c565b19b1"
+            )
+        )
+    );
+    // u = 0 takes the exceptional case x1 = B / (Z * A).
+    let p = map_to_curve_simple_swu(p256::FieldElement::from_literal(0));
+    assert!(p256::is_point_on_curve(p));
+}
@@ -0,0 +1,82 @@
+extern crate hacspec;
+use hacspec::prelude::*;
+
+extern crate hacspecs;
+use hacspecs::curve25519::{secret_to_public, SerializedPoint, SerializedScalar};
+use hacspecs::nacl::*;
+use hacspecs::salsa20::{Key, XIV};
+
+const ALICE_SK: &str = "77076d0a7318a57d3c16c17251b26645df4c2f87ebc0992ab177fba51db92c2a";
+const ALICE_PK: &str = "8520f0098930a754748b7ddcb43ef75a0dbf3a0d26381af4eba4a98eaa9b4e6a";
+const BOB_SK: &str = "5dab087e624a8a4b79e17f8b83800ee66f3bb1292618b6fd1c2f8b27ff88e0eb";
+const BOB_PK: &str = "de9edb7d7b7dc1b4d35b61c2ece435373f8343c85b78674dadfc7e146f882b4f";
+const NONCE: &str = "69696ee955b62b73cd62bda875fc73d68219e0036b7a0b37";
+const MSG: &str = "be075fc53c81f2d5cf141316ebeb0c7b5228c52a4c62cbd44b66849b64244ffce5ecbaaf33bd751a1ac728d45e6c61296cdc3c01233561f41db66cce314adb310e3be8250c46f06dceea3a7fa1348057e2f6556ad6b1318a024a838f21af1fde048977eb48f59ffd4924ca1c60902e52f0a089bc76897040e082f937763848645e0705";
+const BOXED: &str = "f3ffc7703f9400e52a7dfb4b3d3305d98e993b9f48681273c29650ba32fc76ce48332ea7164d96a4476fb8c531a1186ac0dfc17c98dce87b4da7f011ec48c97271d2c20f9b928fe2270d6fb863d51738b48eeee314a7cc8ab932164548e526ae90224368517acfeabd6bb3732bc0e9da99832b61ca01b6de56244a9e88d5f9b37973f622a43d14a6599b1f654cb45a74e355a5";
+const FIRST_KEY: &str = "1b27556473e985d462cd51197a9a46c76009549eac6474f206c4ee0844f68389";
+
+// NaCl tests/box.c and tests/box2.c
+#[test]
+fn test_box_kat() {
+    let iv = XIV::from(NONCE);
+    let boxed = crypto_box(
+        SerializedPoint::from(BOB_PK),
+        SerializedScalar::from(ALICE_SK),
+        iv,
+        ByteSeq::from(MSG),
+    );
+    assert_eq!(BOXED, boxed.to_hex());
+
+    let msg = box_open(
+        SerializedPoint::from(ALICE_PK),
+        SerializedScalar::from(BOB_SK),
+        iv,
+        boxed,
+    )
+    .unwrap();
+    assert_eq!(MSG, msg.to_hex());
+}
+
+// NaCl tests/secretbox.c and tests/secretbox2.c
+#[test]
+fn test_secretbox_kat() {
+    let k = Key::from(FIRST_KEY);
+    let iv = XIV::from(NONCE);
+    let boxed = secretbox(k, iv, ByteSeq::from(MSG));
+    assert_eq!(BOXED, boxed.to_hex());
+    let msg = secretbox_open(k, iv, boxed).unwrap();
+    assert_eq!(MSG, msg.to_hex());
+}
+
+#[test]
+fn test_beforenm() {
+    let k_alice = box_beforenm(
+        SerializedPoint::from(BOB_PK),
+        SerializedScalar::from(ALICE_SK),
+    );
+    let k_bob = box_beforenm(
+        SerializedPoint::from(ALICE_PK),
+        SerializedScalar::from(BOB_SK),
+    );
+    assert_bytes_eq!(Key::from(FIRST_KEY), k_alice);
+    assert_bytes_eq!(k_alice, k_bob);
+}
+
+#[test]
+fn test_enc_dec() {
+    let sk_a = SerializedScalar::random();
+    let sk_b = SerializedScalar::random();
+    let pk_a = secret_to_public(sk_a);
+    let pk_b = secret_to_public(sk_b);
+    let iv = XIV::random();
+    let m = ByteSeq::random(40);
+    let boxed = crypto_box(pk_b, sk_a, iv, m.clone());
+    let m_dec = box_open(pk_a, sk_b, iv, boxed.clone()).unwrap();
+    assert_bytes_eq!(m, m_dec);
+
+    // Flip a bit in the cipher text.
+    let mut forged = boxed;
+    forged[20] ^= U8(1);
+    assert!(box_open(pk_a, sk_b, iv, forged).is_err());
+    assert!(secretbox_open(Key::random(), iv, ByteSeq::new(15)).is_err());
+}
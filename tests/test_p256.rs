@@ -1,3 +1,6 @@
+extern crate hacspec;
+use hacspec::prelude::*;
+
 extern crate hacspecs;
 use hacspecs::p256::*;
 
@@ -9,7 +12,7 @@ fn test_gmac() {
         FieldElement::from_hex("76E49B6DE2F73234AE6A5EB9D612B75C9F2202BB6923F54FF8240AAA86F640B8"),
     );
 
-    let point_computed = point_mul(sk);
+    let point_computed = point_mul_base(sk).unwrap();
     assert_eq!(point_computed, point_expected);
 
     let sk = Scalar::from_hex("018ebbb95eed0e13");
@@ -18,7 +21,7 @@ fn test_gmac() {
         FieldElement::from_hex("B1C14DDFDC8EC1B2583F51E85A5EB3A155840F2034730E9B5ADA38B674336A21"),
     );
 
-    let point_computed = point_mul(sk);
+    let point_computed = point_mul_base(sk).unwrap();
     assert_eq!(point_computed, point_expected);
 
     let sk = Scalar::from_hex("ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632550");
@@ -27,6 +30,222 @@ fn test_gmac() {
         FieldElement::from_hex("B01CBD1C01E58065711814B583F061E9D431CCA994CEA1313449BF97C840AE0A"),
     );
 
-    let point_computed = point_mul(sk);
+    let point_computed = point_mul_base(sk).unwrap();
     assert_eq!(point_computed, point_expected);
 }
+
+struct EcdhTestVector<'a> {
+    peer_x: &'a str,
+    peer_y: &'a str,
+    sk: &'a str,
+    pk_x: &'a str,
+    pk_y: &'a str,
+    shared: &'a str,
+}
+
+// NIST CAVP KAS ECC CDH primitive test vectors
+const ECDH_KAT: [EcdhTestVector; 2] = [
+    EcdhTestVector {
+        peer_x: "700c48f77f56584c5cc632ca65640db91b6bacce3a4df6b42ce7cc838833d287",
+        peer_y: "db71e509e3fd9b060ddb20ba5c51dcc5948d46fbf640dfe0441782cab85fa4ac",
+        sk: "7d7dc5f71eb29ddaf80d6214632eeae03d9058af1fb6d22ed80badb62bc1a534",
+        pk_x: "ead218590119e8876b29146ff89ca61770c4edbbf97d38ce385ed281d8a6b230",
+        pk_y: "28af61281fd35e2fa7002523acc85a429cb06ee6648325389f59edfce1405141",
+        shared: "46fc62106420ff012e54a434fbdd2d25ccc5852060561e68040dd7778997bd7b",
+    },
+    EcdhTestVector {
+        peer_x: "809f04289c64348c01515eb03d5ce7ac1a8cb9498f5caa50197e58d43a86a7ae",
+        peer_y: "b29d84e811197f25eba8f5194092cb6ff440e26d4421011372461f579271cda3",
+        sk: "38f65d6dce47676044d58ce5139582d568f64bb16098d179dbab07741dd5caf5",
+        pk_x: "119f2f047902782ab0c9e27a54aff5eb9b964829ca99c06b02ddba95b0a3f6d0",
+        pk_y: "8f52b726664cac366fc98ac7a012b2682cbd962e5acb544671d41b9445704d1d",
+        shared: "057d636096cb80b67a8c038c890e887d1adfa4195e9b3ce241c8a778c59cda67",
+    },
+];
+
+#[test]
+fn test_ecdh() {
+    for kat in ECDH_KAT.iter() {
+        let sk = Scalar::from_hex(kat.sk);
+        let pk = point_mul_base(sk).unwrap();
+        assert_eq!(
+            pk,
+            (
+                FieldElement::from_hex(kat.pk_x),
+                FieldElement::from_hex(kat.pk_y)
+            )
+        );
+        let peer = (
+            FieldElement::from_hex(kat.peer_x),
+            FieldElement::from_hex(kat.peer_y),
+        );
+        let shared = ecdh_shared_secret(sk, peer).unwrap();
+        assert_eq!(kat.shared, shared.to_hex());
+    }
+}
+
+#[test]
+fn test_ecdh_invalid() {
+    let kat = &ECDH_KAT[0];
+    let sk = Scalar::from_hex(kat.sk);
+    let peer = (
+        FieldElement::from_hex(kat.peer_x),
+        FieldElement::from_hex(kat.peer_y),
+    );
+    // Not on the curve
+    let off_curve = (peer.0, peer.1 + FieldElement::from_literal(1));
+    assert!(ecdh(sk, off_curve).is_err());
+
+    // The shared point is the point at infinity for sk = 0 and sk = n.
+    assert!(ecdh(Scalar::from_hex("00"), peer).is_err());
+    let n = Scalar::from_hex("ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551");
+    assert!(ecdh(n, peer).is_err());
+}
+
+#[test]
+fn test_sec1() {
+    for kat in ECDH_KAT.iter() {
+        let p = (
+            FieldElement::from_hex(kat.peer_x),
+            FieldElement::from_hex(kat.peer_y),
+        );
+        let uncompressed = serialize_uncompressed(p);
+        assert_eq!(
+            format!("04{}{}", kat.peer_x, kat.peer_y),
+            uncompressed.to_hex()
+        );
+        assert_eq!(deserialize_uncompressed(uncompressed).unwrap(), p);
+
+        let compressed = serialize_compressed(p);
+        assert_eq!(&compressed.to_hex()[2..], kat.peer_x);
+        assert_eq!(deserialize_compressed(compressed).unwrap(), p);
+        assert_eq!(deserialize(ByteSeq::from(compressed.raw())).unwrap(), p);
+        assert_eq!(deserialize(ByteSeq::from(uncompressed.raw())).unwrap(), p);
+    }
+    // The second peer point has an odd y and the first an even y.
+    assert_eq!(
+        "03",
+        &serialize_compressed((
+            FieldElement::from_hex(ECDH_KAT[1].peer_x),
+            FieldElement::from_hex(ECDH_KAT[1].peer_y)
+        ))
+        .to_hex()[0..2]
+    );
+}
+
+#[test]
+fn test_sec1_invalid() {
+    let kat = &ECDH_KAT[0];
+    // Wrong prefix
+    let mut p = serialize_uncompressed((
+        FieldElement::from_hex(kat.peer_x),
+        FieldElement::from_hex(kat.peer_y),
+    ));
+    p[0] = U8(0x05);
+    assert!(deserialize_uncompressed(p).is_err());
+
+    // Not on the curve
+    let p = UncompressedPoint::from(format!("04{}{}", kat.peer_x, kat.peer_x).as_str());
+    assert!(deserialize_uncompressed(p).is_err());
+
+    // x^3 - 3x + b is not a square for x = 1
+    let p =
+        CompressedPoint::from("020000000000000000000000000000000000000000000000000000000000000001");
+    assert!(deserialize_compressed(p).is_err());
+
+    // x = p is not reduced
+    let p =
+        CompressedPoint::from("02ffffffff00000001000000000000000000000000ffffffffffffffffffffffff");
+    assert!(deserialize_compressed(p).is_err());
+
+    // Wrong length
+    assert!(deserialize(ByteSeq::new(64)).is_err());
+}
+
+fn g2() -> Projective {
+    affine_to_projective((
+        FieldElement::from_hex("7cf27b188d034f7e8a52380304b51ac3c08969e277f21b35a60b48fc47669978"),
+        FieldElement::from_hex("07775510db8ed040293d9ac69f7430dbba7dade63ce982299e04b79d227873d1"),
+    ))
+}
+
+fn g3() -> Projective {
+    affine_to_projective((
+        FieldElement::from_hex("5ecbe4d1a6330a44c8f7ef951d4bf165e6c6b721efada985fb41661bc6e7fd6c"),
+        FieldElement::from_hex("8734640c4998ff7e374b06ce1a64a2ecd82ab036384fb83d9a79b127a27d5032"),
+    ))
+}
+
+#[test]
+fn test_point_add_complete() {
+    let g = base_point();
+    // P + Q
+    assert!(point_eq(point_add(g, g2()), g3()));
+    assert!(point_eq(point_add(g2(), g), g3()));
+
+    // P + P
+    assert!(point_eq(point_add(g, g), g2()));
+    assert!(point_eq(point_add(g, g), point_double(g)));
+    // Equal points with different Z
+    let (x, y, z) = g;
+    let g_scaled = (
+        x * FieldElement::from_literal(7),
+        y * FieldElement::from_literal(7),
+        z * FieldElement::from_literal(7),
+    );
+    assert!(point_eq(point_add(g, g_scaled), g2()));
+
+    // P + (-P)
+    assert!(is_point_at_infinity(point_add(g, point_neg(g))));
+    assert!(is_point_at_infinity(point_add(g2(), point_neg(g2()))));
+
+    // O + P, P + O and O + O
+    let o = point_identity();
+    assert!(point_eq(point_add(o, g), g));
+    assert!(point_eq(point_add(g, o), g));
+    assert!(is_point_at_infinity(point_add(o, o)));
+    assert!(is_point_at_infinity(point_double(o)));
+    assert_eq!(
+        projective_to_affine(point_add(o, g3())),
+        projective_to_affine(g3())
+    );
+}
+
+#[test]
+fn test_point_mul_cross_check() {
+    let g = projective_to_affine(base_point());
+    let scalars = [
+        "01",
+        "02",
+        "018ebbb95eed0e13",
+        "7d7dc5f71eb29ddaf80d6214632eeae03d9058af1fb6d22ed80badb62bc1a534",
+        "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632550",
+    ];
+    for k in scalars.iter() {
+        let k = Scalar::from_hex(k);
+        // Variable-base and fixed-base multiplication agree.
+        assert_eq!(point_mul(k, g).unwrap(), point_mul_base(k).unwrap());
+        // Shamir's trick agrees with two separate multiplications.
+        let q = point_mul_base(Scalar::from_hex("2a")).unwrap();
+        let expected = point_add(
+            affine_to_projective(point_mul_base(k).unwrap()),
+            affine_to_projective(point_mul(Scalar::from_hex("1234"), q).unwrap()),
+        );
+        let computed = point_mul_add(k, Scalar::from_hex("1234"), q).unwrap();
+        assert_eq!(computed, projective_to_affine(expected));
+        // k * G + 0 * Q and 0 * G + k * Q
+        assert_eq!(
+            point_mul_add(k, Scalar::from_hex("00"), q).unwrap(),
+            point_mul_base(k).unwrap()
+        );
+        assert_eq!(
+            point_mul_add(Scalar::from_hex("00"), k, q).unwrap(),
+            point_mul(k, q).unwrap()
+        );
+    }
+
+    // 1 * G + (n - 1) * G is the point at infinity.
+    let n_1 = Scalar::from_hex("ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632550");
+    assert!(point_mul_add(Scalar::from_hex("01"), n_1, g).is_err());
+    assert!(point_mul_base(Scalar::from_hex("00")).is_err());
+}
@@ -2,7 +2,7 @@ extern crate hacspec;
 use hacspec::prelude::*;
 
 extern crate hacspecs;
-use hacspecs::{chacha20::*, poly1305::*};
+use hacspecs::{aes, chacha20::*, poly1305::*};
 
 #[test]
 fn basic_test() {
@@ -29,3 +29,88 @@ fn basic_test() {
     let computed = poly(msg, k);
     assert_bytes_eq!(expected, computed);
 }
+
+#[test]
+fn test_incremental() {
+    let key = Key::random();
+    let m = ByteSeq::random(100);
+    let expected = poly(m.clone(), key);
+    let splits = [
+        (0usize, 0usize),
+        (1, 15),
+        (15, 1),
+        (16, 16),
+        (7, 50),
+        (33, 0),
+    ];
+    for &(first, second) in splits.iter() {
+        let state = Poly1305::new(key)
+            .update(m.sub(0, first))
+            .update(m.sub(first, second))
+            .update(m.sub(first + second, m.len() - first - second));
+        let computed = state.clone().finalize();
+        assert_bytes_eq!(expected, computed);
+        assert!(state.verify(expected));
+    }
+}
+
+#[test]
+fn test_verify() {
+    // RFC 7539 Test Vectors
+    let msg = ByteSeq::from("43727970746f6772617068696320466f72756d2052657365617263682047726f7570");
+    let k = Key::from("85d6be7857556d337f4452fe42d506a80103808afb0db2fd4abff6af4149f51b");
+    let tag = Tag::from("a8061dc1305136c6c22b8baf0c0127a9");
+    let state = Poly1305::new(k)
+        .update(msg.sub(0, 20))
+        .update(msg.sub(20, 14));
+    assert!(state.clone().verify(tag));
+    let mut forged = tag;
+    forged[15] ^= U8(0x80);
+    assert!(!state.verify(forged));
+}
+
+struct PolyAesTestVector<'a> {
+    msg: &'a str,
+    r: &'a str,
+    k: &'a str,
+    nonce: &'a str,
+    tag: &'a str,
+}
+
+// https://cr.yp.to/mac/poly1305-20050329.pdf Appendix B
+const POLY_AES_KAT: [PolyAesTestVector; 3] = [
+    PolyAesTestVector {
+        msg: "f3f6",
+        r: "851fc40c3467ac0be05cc20404f3f700",
+        k: "ec074c835580741701425b623235add6",
+        nonce: "fb447350c4e868c52ac3275cf9d4327e",
+        tag: "f4c633c3044fc145f84f335cb81953de",
+    },
+    PolyAesTestVector {
+        msg: "",
+        r: "a0f3080000f46400d0c7e9076c834403",
+        k: "75deaa25c09f208e1dc4ce6b5cad3fbf",
+        nonce: "61ee09218d29b0aaed7e154a2c5509cc",
+        tag: "dd3fab2251f11ac759f0887129cc2ee7",
+    },
+    PolyAesTestVector {
+        msg: "663cea190ffb83d89593f3f476b6bc24d7e679107ea26adb8caf6652d0656136",
+        r: "48443d0bb0d21109c89a100b5ce2c208",
+        k: "6acb5f61a7176dd320c5c1eb2edcdc74",
+        nonce: "ae212a55399729595dea458bc621ff0e",
+        tag: "0ee1c16bb73f0f4fd19881753c01cdbe",
+    },
+];
+
+#[test]
+fn test_poly_aes_kat() {
+    for kat in POLY_AES_KAT.iter() {
+        let tag = poly_aes(
+            ByteSeq::from(kat.msg),
+            aes::Key::from(kat.k),
+            Block::from(kat.r),
+            aes::Block::from(kat.nonce),
+        );
+        assert_eq!(kat.tag, tag.to_hex());
+    }
+}
@@ -0,0 +1,116 @@
+extern crate hacspec;
+use hacspec::prelude::*;
+
+extern crate hacspecs;
+use hacspecs::ed25519::Scalar;
+use hacspecs::ristretto::*;
+use hacspecs::sha2::sha512;
+
+// https://www.rfc-editor.org/rfc/rfc9496#appendix-A.1
+const MULTIPLES: [&str; 16] = [
+    "0000000000000000000000000000000000000000000000000000000000000000",
+    "e2f2ae0a6abc4e71a884a961c500515f58e30b6aa582dd8db6a65945e08d2d76",
+    "6a493210f7499cd17fecb510ae0cea23a110e8d5b901f8acadd3095c73a3b919",
+    "94741f5d5d52755ece4f23f044ee27d5d1ea1e2bd196b462166b16152a9d0259",
+    "da80862773358b466ffadfe0b3293ab3d9fd53c5ea6c955358f568322daf6a57",
+    "e882b131016b52c1d3337080187cf768423efccbb517bb495ab812c4160ff44e",
+    "f64746d3c92b13050ed8d80236a7f0007c3b3f962f5ba793d19a601ebb1df403",
+    "44f53520926ec81fbd5a387845beb7df85a96a24ece18738bdcfa6a7822a176d",
+    "903293d8f2287ebe10e2374dc1a53e0bc887e592699f02d077d5263cdd55601c",
+    "02622ace8f7303a31cafc63f8fc48fdc16e1c8c8d234b2f0d6685282a9076031",
+    "20706fd788b2720a1ed2a5dad4952b01f413bcf0e7564de8cdc816689e2db95f",
+    "bce83f8ba5dd2fa572864c24ba1810f9522bc6004afe95877ac73241cafdab42",
+    "e4549ee16b9aa03099ca208c67adafcafa4c3f3e4e5303de6026e3ca8ff84460",
+    "aa52e000df2e16f55fb1032fc33bc42742dad6bd5a8fc0be0167436c5948501f",
+    "46376b80f409b29dc2b5f6f0c52591990896e5716f41477cd30085ab7f10301e",
+    "e0c418f7c8d9c4cdd7395b93ea124f3ad99021bb681dfc3302a9d99a2e53e64e",
+];
+
+#[test]
+fn test_multiples() {
+    let mut p = identity();
+    for (i, expected) in MULTIPLES.iter().enumerate() {
+        assert_eq!(*expected, encode(p).to_hex());
+        let q = decode(RistrettoPointEncoded::from(*expected)).unwrap();
+        assert!(equals(p, q));
+        assert_eq!(*expected, encode(q).to_hex());
+        assert!(equals(p, mul(Scalar::from_literal(i as u128), generator())));
+        p = add(p, generator());
+    }
+}
+
+// https://www.rfc-editor.org/rfc/rfc9496#appendix-A.2
+const INVALID: [&str; 9] = [
+    // Non-canonical field encodings
+    "00ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+    "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f",
+    "f3ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f",
+    "edffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f",
+    // Negative field elements
+    "0100000000000000000000000000000000000000000000000000000000000000",
+    "01ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f",
+    // Non-square x^2
+    "26948d35ca62e643e26a83177332e6b6afeb9d08e4268b650f1f5bbd8d81d371",
+    "2eb10d432702ea7f79207da95d206f82d5a3b374f5f89f17a199531f78d3bea6",
+    // s = -0
+    "0000000000000000000000000000000000000000000000000000000000000080",
+];
+
+#[test]
+fn test_invalid_encodings() {
+    for enc in INVALID.iter() {
+        assert!(decode(RistrettoPointEncoded::from(*enc)).is_err());
+    }
+}
+
+// https://www.rfc-editor.org/rfc/rfc9496#appendix-A.3
+// The inputs to the map are the SHA-512 hashes of the messages.
+const HASH_TO_GROUP: [(&str, &str); 7] = [
+    (
+        "52697374726574746f20697320747261646974696f6e616c6c7920612073686f72742073686f74206f6620657370726573736f20636f66666565",
+        "3066f82a1a747d45120d1740f14358531a8f04bbffe6a819f86dfe50f44a0a46",
+    ),
+    (
+        "6d616465207769746820746865206e6f726d616c20616d6f756e74206f662067726f756e6420636f6666656520627574206578747261637465642077697468",
+        "f26e5b6f7d362d2d2a94c5d0e7602cb4773c95a2e5c31a64f133189fa76ed61b",
+    ),
+    (
+        "61626f75742068616c662074686520616d6f756e74206f6620776174657220696e207468652073616d6520616d6f756e74206f662074696d65",
+        "006ccd2a9e6867e6a2c5cea83d3302cc9de128dd2a9a57dd8ee7b9d7ffe02826",
+    ),
+    (
+        "6279207573696e6720612066696e6572206772696e642e",
+        "f8f0c87cf237953c5890aec3998169005dae3eca1fbb04548c635953c817f92a",
+    ),
+    (
+        "546869732070726f6475636573206120636f6e63656e7472617465642073686f74206f6620636f666665652070657220766f6c756d652e",
+        "ae81e7dedf20a497e10c304a765c1767a42d6e06029758d2d7e8ef7cc4c41179",
+    ),
+    (
+        "4a7573742070756c6c696e672061206e6f726d616c2073686f742073686f72742077696c6c2070726f647563652061207765616b65722073686f74",
+        "e2705652ff9f5e44d3e841bf1c251cf7dddb77d140870d1ab2ed64f1a9ce8628",
+    ),
+    (
+        "616e64206973206e6f7420612052697374726574746f20617320736f6d652062656c696576652e",
+        "80bd07262511cdde4863f8a7434cef696750681cb9510eea557088f76d9e5065",
+    ),
+];
+
+#[test]
+fn test_hash_to_group() {
+    for (msg, expected) in HASH_TO_GROUP.iter() {
+        let h = sha512(ByteSeq::from(*msg));
+        let p = from_uniform_bytes(UniformBytes::from(ByteSeq::from(h.raw())));
+        assert_eq!(*expected, encode(p).to_hex());
+    }
+}
+
+#[test]
+fn test_group() {
+    let p = from_uniform_bytes(UniformBytes::random());
+    let q = from_uniform_bytes(UniformBytes::random());
+    assert!(equals(add(p, q), add(q, p)));
+    assert!(equals(add(p, neg(p)), identity()));
+    let r = decode(encode(add(p, q))).unwrap();
+    assert!(equals(r, add(p, q)));
+}
@@ -0,0 +1,61 @@
+extern crate hacspec;
+use hacspec::prelude::*;
+
+extern crate hacspecs;
+use hacspecs::chacha20::{State, StateBytes};
+use hacspecs::salsa20::*;
+
+// https://cr.yp.to/snuffle/spec.pdf
+#[test]
+fn test_quarter_round() {
+    let mut state = State::new();
+    state[0] = U32(1);
+    state = quarter_round(0, 1, 2, 3, state);
+    let mut expected_state = State::new();
+    expected_state[0] = U32(0x08008145);
+    expected_state[1] = U32(0x00000080);
+    expected_state[2] = U32(0x00010200);
+    expected_state[3] = U32(0x20500000);
+    assert_secret_array_eq!(expected_state, state, U32);
+}
+
+// https://tools.ietf.org/html/rfc7914#section-8
+#[test]
+fn test_salsa20_8() {
+    let input = StateBytes::from("7e879a214f3ec9867ca940e641718f26baee555b8c61c1b50df846116dcd3b1dee24f319df9b3d8514121e4b5ac5aa3276021d2909c74829edebc68db8b8c25e");
+    let expected = StateBytes::from("a41f859c6608cc993b81cacb020cef05044b2181a2fd337dfd7b1c6396682f29b4393168e3c9e6bcfe6bc5b7a06d96bae424cc102c91745c24ad673dc7618f81");
+    assert_bytes_eq!(expected, salsa20_8(input));
+}
+
+// eSTREAM Salsa20/20, 256 bit key, Set 1, vector# 0
+#[test]
+fn test_salsa20_kat() {
+    let key = Key::from("8000000000000000000000000000000000000000000000000000000000000000");
+    let iv = IV::new();
+    let expected = "e3be8fdd8beca2e3ea8ef9475b29a6e7003951e1097a5c38d23b7a5fad9f6844b22c97559e2723c7cbbd3fe4fc8d9a0744652a83e72a9c461876af4d7ef1a117";
+    let keystream = salsa20(key, iv, ByteSeq::new(64));
+    assert_eq!(expected, keystream.to_hex());
+}
+
+#[test]
+fn test_enc_dec() {
+    let key = Key::random();
+    let iv = XIV::random();
+    let m = ByteSeq::random(200);
+    let c = xsalsa20(key, iv, m.clone());
+    let m_dec = xsalsa20(key, iv, c);
+    assert_bytes_eq!(m, m_dec);
+}
+
+// NaCl tests/core1.c and tests/stream3.c
+#[test]
+fn test_xsalsa20_kat() {
+    let shared = Key::from("4a5d9d5ba4ce2de1728e3bf480350f25e07e21c947d19e3376f09b3c1e161742");
+    let first_key = Key::from("1b27556473e985d462cd51197a9a46c76009549eac6474f206c4ee0844f68389");
+    assert_bytes_eq!(first_key, hsalsa(shared, HSalsaIV::new()));
+
+    let iv = XIV::from("69696ee955b62b73cd62bda875fc73d68219e0036b7a0b37");
+    let expected = "eea6a7251c1e72916d11c2cb214d3c252539121d8e234e652d651fa4c8cff880";
+    let keystream = xsalsa20(first_key, iv, ByteSeq::new(32));
+    assert_eq!(expected, keystream.to_hex());
+}
@@ -0,0 +1,90 @@
+extern crate hacspec;
+use hacspec::prelude::*;
+
+extern crate hacspecs;
+use hacspecs::secp256k1::*;
+
+fn g2() -> Affine {
+    (
+        FieldElement::from_hex("c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5"),
+        FieldElement::from_hex("1ae168fea63dc339a3c58419466ceaeef7f632653266d0e1236431a950cfe52a"),
+    )
+}
+
+fn g3() -> Affine {
+    (
+        FieldElement::from_hex("f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9"),
+        FieldElement::from_hex("388f7b0f632de8140fe337e62a37f3566500a99934c2231b6cb9fd7584b8e672"),
+    )
+}
+
+#[test]
+fn test_point_add_complete() {
+    let g = base_point();
+    assert!(is_point_on_curve(projective_to_affine(g)));
+
+    // a = 0 doubling
+    assert!(point_eq(point_double(g), affine_to_projective(g2())));
+    // P + P with the addition formulas
+    assert!(point_eq(point_add(g, g), affine_to_projective(g2())));
+    assert!(point_eq(
+        point_add(affine_to_projective(g2()), g),
+        affine_to_projective(g3())
+    ));
+    // P + O, O + O and P + (-P)
+    assert!(point_eq(point_add(g, point_identity()), g));
+    assert!(is_point_at_infinity(point_add(
+        point_identity(),
+        point_identity()
+    )));
+    assert!(is_point_at_infinity(point_double(point_identity())));
+    assert!(is_point_at_infinity(point_add(g, point_neg(g))));
+}
+
+#[test]
+fn test_point_mul() {
+    assert_eq!(point_mul_base(Scalar::from_hex("03")).unwrap(), g3());
+    assert_eq!(
+        point_mul(Scalar::from_hex("02"), g2()).unwrap(),
+        point_mul_base(Scalar::from_hex("04")).unwrap()
+    );
+    // n - 1
+    let n_1 = Scalar::from_hex("fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364140");
+    let g = projective_to_affine(base_point());
+    assert_eq!(
+        point_mul_base(n_1).unwrap(),
+        projective_to_affine(point_neg(base_point()))
+    );
+    assert!(point_mul_add(Scalar::from_hex("01"), n_1, g).is_err());
+    assert_eq!(
+        point_mul_add(Scalar::from_hex("01"), Scalar::from_hex("01"), g2()).unwrap(),
+        g3()
+    );
+
+    // Diffie-Hellman
+    let a = Scalar::from_hex("0b432b2677937381aef05bb02a66ecd012773062cf3fa2549e44f58ed2401710");
+    let b = Scalar::from_hex("c90fdaa22168c234c4c6628b80dc1cd129024e088a67cc74020bbea63b14e5c9");
+    let ab = ecdh_shared_secret(a, point_mul_base(b).unwrap()).unwrap();
+    let ba = ecdh_shared_secret(b, point_mul_base(a).unwrap()).unwrap();
+    assert_eq!(ab.to_hex(), ba.to_hex());
+}
+
+#[test]
+fn test_sec1() {
+    let g = projective_to_affine(base_point());
+    let compressed = serialize_compressed(g);
+    assert_eq!(
+        "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+        compressed.to_hex()
+    );
+    assert_eq!(deserialize_compressed(compressed).unwrap(), g);
+    assert_eq!(
+        deserialize_uncompressed(serialize_uncompressed(g3())).unwrap(),
+        g3()
+    );
+
+    // x = p is not reduced
+    let p =
+        CompressedPoint::from("02fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f");
+    assert!(deserialize_compressed(p).is_err());
+}
@@ -1,7 +1,7 @@
 use hacspec::prelude::*;
 
 extern crate hacspecs;
-use hacspecs::sha2::hash;
+use hacspecs::sha2::{hash, sha384, sha512};
 
 #[test]
 fn test_sha256_kat() {
@@ -19,3 +19,86 @@ fn test_sha256_kat() {
     println!("{:x?}", digest);
     assert_eq!(expected_256, digest.to_hex());
 }
+
+#[test]
+fn test_sha256_padding() {
+    // Messages that fill whole blocks need an extra padding block.
+    let kat = [
+        (0, "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"),
+        (64, "ffe054fe7ae0cb6dc65c3af9b61d5209f439851db43d0ba5997337df154668eb"),
+        (128, "6836cf13bac400e9105071cd6af47084dfacad4e5e302c94bfed24e013afb73e"),
+    ];
+    for (len, expected) in kat.iter() {
+        let mut msg = ByteSeq::new(*len);
+        for i in 0..*len {
+            msg[i] = U8(0x61);
+        }
+        let digest = hash(msg);
+        assert_eq!(*expected, digest.to_hex());
+    }
+}
+
+#[test]
+fn test_sha512_kat() {
+    let kat = [
+        (
+            "",
+            "cf83e1357eefb8bdf1542850d66d8007d620e4050b5715dc83f4a921d36ce9ce47d0d13c5d85f2b0ff8318d2877eec2f63b931bd47417a81a538327af927da3e",
+        ),
+        (
+            "616263",
+            "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f",
+        ),
+    ];
+    for (msg, expected) in kat.iter() {
+        let digest = sha512(ByteSeq::from(*msg));
+        assert_eq!(*expected, digest.to_hex());
+    }
+
+    // Messages around the block and padding boundaries
+    let kat = [
+        (111, "fa9121c7b32b9e01733d034cfc78cbf67f926c7ed83e82200ef86818196921760b4beff48404df811b953828274461673c68d04e297b0eb7b2b4d60fc6b566a2"),
+        (112, "c01d080efd492776a1c43bd23dd99d0a2e626d481e16782e75d54c2503b5dc32bd05f0f1ba33e568b88fd2d970929b719ecbb152f58f130a407c8830604b70ca"),
+        (128, "b73d1929aa615934e61a871596b3f3b33359f42b8175602e89f7e06e5f658a243667807ed300314b95cacdd579f3e33abdfbe351909519a846d465c59582f321"),
+        (239, "52c853cb8d907f3d4d6b889beb027985d7c273486d75f8baf26f80d24e90c74c6c3de3e22131582380a7d14d43f2941a31385439cd6ddc469f628015e50bf286"),
+    ];
+    for (len, expected) in kat.iter() {
+        let mut msg = ByteSeq::new(*len);
+        for i in 0..*len {
+            msg[i] = U8(0x61);
+        }
+        let digest = sha512(msg);
+        assert_eq!(*expected, digest.to_hex());
+    }
+}
+
+#[test]
+fn test_sha384_kat() {
+    let kat = [
+        (
+            "",
+            "38b060a751ac96384cd9327eb1b1e36a21fdb71114be07434c0cc7bf63f6e1da274edebfe76f65fbd51ad2f14898b95b",
+        ),
+        (
+            "616263",
+            "cb00753f45a35e8bb5a03d699ac65007272c32ab0eded1631a8b605a43ff5bed8086072ba1e7cc2358baeca134c825a7",
+        ),
+    ];
+    for (msg, expected) in kat.iter() {
+        let digest = sha384(ByteSeq::from(*msg));
+        assert_eq!(*expected, digest.to_hex());
+    }
+
+    let kat = [
+        (111, "3c37955051cb5c3026f94d551d5b5e2ac38d572ae4e07172085fed81f8466b8f90dc23a8ffcdea0b8d8e58e8fdacc80a"),
+        (112, "187d4e07cb306103c69967bf544d0dfbe9042577599c73c330abc0cb64c61236d5ed565ee19119d8c31779a38f791fcd"),
+    ];
+    for (len, expected) in kat.iter() {
+        let mut msg = ByteSeq::new(*len);
+        for i in 0..*len {
+            msg[i] = U8(0x61);
+        }
+        let digest = sha384(msg);
+        assert_eq!(*expected, digest.to_hex());
+    }
+}
@@ -0,0 +1,217 @@
+extern crate hacspec;
+use hacspec::prelude::*;
+
+extern crate hacspecs;
+
+struct EcdhTestVector<'a> {
+    peer_x: &'a str,
+    peer_y: &'a str,
+    sk: &'a str,
+    pk_x: &'a str,
+    pk_y: &'a str,
+    shared: &'a str,
+}
+
+// NIST CAVP KAS ECC CDH primitive test vectors (ecccdhtestvectors.zip) and
+// the ECDH groups of RFC 5903. For the RFC vectors the peer point is gr, the
+// secret key i, the public key gi and the shared secret the x-coordinate of
+// gir.
+const P384_ECDH_KAT: [EcdhTestVector; 3] = [
+    // [P-384] COUNT = 0, 1
+    EcdhTestVector {
+        peer_x: "a7c76b970c3b5fe8b05d2838ae04ab47697b9eaf52e764592efda27fe7513272734466b400091adbf2d68c58e0c50066",
+        peer_y: "ac68f19f2e1cb879aed43a9969b91a0839c4c38a49749b661efedf243451915ed0905a32b060992b468c64766fc8437a",
+        sk: "3cc3122a68f0d95027ad38c067916ba0eb8c38894d22e1b15618b6818a661774ad463b205da88cf699ab4d43c9cf98a1",
+        pk_x: "9803807f2f6d2fd966cdd0290bd410c0190352fbec7ff6247de1302df86f25d34fe4a97bef60cff548355c015dbb3e5f",
+        pk_y: "ba26ca69ec2f5b5d9dad20cc9da711383a9dbe34ea3fa5a2af75b46502629ad54dd8b7d73a8abb06a3a3be47d650cc99",
+        shared: "5f9d29dc5e31a163060356213669c8ce132e22f57c9a04f40ba7fcead493b457e5621e766c40a2e3d4d6a04b25e533f1",
+    },
+    EcdhTestVector {
+        peer_x: "30f43fcf2b6b00de53f624f1543090681839717d53c7c955d1d69efaf0349b7363acb447240101cbb3af6641ce4b88e0",
+        peer_y: "25e46c0c54f0162a77efcc27b6ea792002ae2ba82714299c860857a68153ab62e525ec0530d81b5aa15897981e858757",
+        sk: "92860c21bde06165f8e900c687f8ef0a05d14f290b3f07d8b3a8cc6404366e5d5119cd6d03fb12dc58e89f13df9cd783",
+        pk_x: "ea4018f5a307c379180bf6a62fd2ceceebeeb7d4df063a66fb838aa35243419791f7e2c9d4803c9319aa0eb03c416b66",
+        pk_y: "68835a91484f05ef028284df6436fb88ffebabcdd69ab0133e6735a1bcfb37203d10d340a8328a7b68770ca75878a1a6",
+        shared: "a23742a2c267d7425fda94b93f93bbcc24791ac51cd8fd501a238d40812f4cbfc59aac9520d758cf789c76300c69d2ff",
+    },
+    // RFC 5903, Section 8.2
+    EcdhTestVector {
+        peer_x: "e558dbef53eecde3d3fccfc1aea08a89a987475d12fd950d83cfa41732bc509d0d1ac43a0336def96fda41d0774a3571",
+        peer_y: "dcfbec7aacf3196472169e838430367f66eebe3c6e70c416dd5f0c68759dd1fff83fa40142209dff5eaad96db9e6386c",
+        sk: "099f3c7034d4a2c699884d73a375a67f7624ef7c6b3c0f160647b67414dce655e35b538041e649ee3faef896783ab194",
+        pk_x: "667842d7d180ac2cde6f74f37551f55755c7645c20ef73e31634fe72b4c55ee6de3ac808acb4bdb4c88732aee95f41aa",
+        pk_y: "9482ed1fc0eeb9cafc4984625ccfc23f65032149e0e144ada024181535a0f38eeb9fcff3c2c947dae69b4c634573a81c",
+        shared: "11187331c279962d93d604243fd592cb9d0a926f422e47187521287e7156c5c4d603135569b9e9d09cf5d4a270f59746",
+    },
+];
+
+const P521_ECDH_KAT: [EcdhTestVector; 2] = [
+    // [P-521] COUNT = 0
+    EcdhTestVector {
+        peer_x: "00685a48e86c79f0f0875f7bc18d25eb5fc8c0b07e5da4f4370f3a9490340854334b1e1b87fa395464c60626124a4e70d0f785601d37c09870ebf176666877a2046d",
+        peer_y: "01ba52c56fc8776d9e8f5db4f0cc27636d0b741bbe05400697942e80b739884a83bde99e0f6716939e632bc8986fa18dccd443a348b6c3e522497955a4f3c302f676",
+        sk: "017eecc07ab4b329068fba65e56a1f8890aa935e57134ae0ffcce802735151f4eac6564f6ee9974c5e6887a1fefee5743ae2241bfeb95d5ce31ddcb6f9edb4d6fc47",
+        pk_x: "00602f9d0cf9e526b29e22381c203c48a886c2b0673033366314f1ffbcba240ba42f4ef38a76174635f91e6b4ed34275eb01c8467d05ca80315bf1a7bbd945f550a5",
+        pk_y: "01b7c85f26f5d4b2d7355cf6b02117659943762b6d1db5ab4f1dbc44ce7b2946eb6c7de342962893fd387d1b73d7a8672d1f236961170b7eb3579953ee5cdc88cd2d",
+        shared: "005fc70477c3e63bc3954bd0df3ea0d1f41ee21746ed95fc5e1fdf90930d5e136672d72cc770742d1711c3c3a4c334a0ad9759436a4d3c5bf6e74b9578fac148c831",
+    },
+    // RFC 5903, Section 8.3
+    EcdhTestVector {
+        peer_x: "00d0b3975ac4b799f5bea16d5e13e9af971d5e9b984c9f39728b5e5739735a219b97c356436adc6e95bb0352f6be64a6c2912d4ef2d0433ced2b6171640012d9460f",
+        peer_y: "015c68226383956e3bd066e797b623c27ce0eac2f551a10c2c724d9852077b87220b6536c5c408a1d2aebb8e86d678ae49cb57091f4732296579ab44fcd17f0fc56a",
+        sk: "0037ade9319a89f4dabdb3ef411aaccca5123c61acab57b5393dce47608172a095aa85a30fe1c2952c6771d937ba9777f5957b2639bab072462f68c27a57382d4a52",
+        pk_x: "0015417e84dbf28c0ad3c278713349dc7df153c897a1891bd98bab4357c9ecbee1e3bf42e00b8e380aeae57c2d107564941885942af5a7f4601723c4195d176ced3e",
+        pk_y: "017cae20b6641d2eeb695786d8c946146239d099e18e1d5a514c739d7cb4a10ad8a788015ac405d7799dc75e7b7d5b6cf2261a6a7f1507438bf01beb6ca3926f9582",
+        shared: "01144c7d79ae6956bc8edb8e7c787c4521cb086fa64407f97894e5e6b2d79b04d1427e73ca4baa240a34786859810c06b3c715a3a8cc3151f2bee417996d19f3ddea",
+    },
+];
+
+// The tests for the curves defined with `weierstrass_curve!`, which only
+// differ in the curve constants and the test vectors.
+macro_rules! curve_tests {
+    (
+        $curve:ident,
+        ecdh_kat: $ecdh_kat:ident,
+        // The group order
+        n: $n:expr,
+        // A compressed point with an x for which there is no y
+        non_square: $non_square:expr,
+        // A compressed point with x = p
+        unreduced: $unreduced:expr,
+        // A length that is neither a compressed nor an uncompressed point
+        wrong_len: $wrong_len:expr,
+        // 3G
+        g3: ($g3_x:expr, $g3_y:expr),
+    ) => {
+        mod $curve {
+            use super::*;
+            use hacspecs::$curve::*;
+
+            #[test]
+            fn test_ecdh() {
+                for kat in $ecdh_kat.iter() {
+                    let sk = Scalar::from_hex(kat.sk);
+                    let pk = point_mul_base(sk).unwrap();
+                    assert_eq!(
+                        pk,
+                        (
+                            FieldElement::from_hex(kat.pk_x),
+                            FieldElement::from_hex(kat.pk_y)
+                        )
+                    );
+                    let peer = (
+                        FieldElement::from_hex(kat.peer_x),
+                        FieldElement::from_hex(kat.peer_y),
+                    );
+                    let shared = ecdh_shared_secret(sk, peer).unwrap();
+                    assert_eq!(kat.shared, shared.to_hex());
+                }
+            }
+
+            #[test]
+            fn test_ecdh_invalid() {
+                let kat = &$ecdh_kat[0];
+                let sk = Scalar::from_hex(kat.sk);
+                let peer = (
+                    FieldElement::from_hex(kat.peer_x),
+                    FieldElement::from_hex(kat.peer_y),
+                );
+                // Not on the curve
+                let off_curve = (peer.0, peer.1 + FieldElement::from_literal(1));
+                assert!(ecdh(sk, off_curve).is_err());
+
+                // The shared point is the point at infinity for sk = 0 and sk = n.
+                assert!(ecdh(Scalar::from_hex("00"), peer).is_err());
+                let n = Scalar::from_hex($n);
+                assert!(ecdh(n, peer).is_err());
+            }
+
+            #[test]
+            fn test_sec1() {
+                // The vectors have points with both an even and an odd y.
+                for kat in $ecdh_kat.iter() {
+                    for (x, y) in [(kat.peer_x, kat.peer_y), (kat.pk_x, kat.pk_y)].iter() {
+                        let p = (FieldElement::from_hex(x), FieldElement::from_hex(y));
+                        let uncompressed = serialize_uncompressed(p);
+                        assert_eq!(format!("04{}{}", x, y), uncompressed.to_hex());
+                        assert_eq!(deserialize_uncompressed(uncompressed).unwrap(), p);
+
+                        let compressed = serialize_compressed(p);
+                        let prefix = if p.1.bit(0) { "03" } else { "02" };
+                        assert_eq!(format!("{}{}", prefix, x), compressed.to_hex());
+                        assert_eq!(deserialize_compressed(compressed).unwrap(), p);
+                        assert_eq!(deserialize(ByteSeq::from(compressed.raw())).unwrap(), p);
+                        assert_eq!(deserialize(ByteSeq::from(uncompressed.raw())).unwrap(), p);
+                    }
+                }
+            }
+
+            #[test]
+            fn test_sec1_invalid() {
+                // x^3 - 3x + b is not a square
+                let p = CompressedPoint::from($non_square);
+                assert!(deserialize_compressed(p).is_err());
+
+                // x = p is not reduced
+                let p = CompressedPoint::from($unreduced);
+                assert!(deserialize_compressed(p).is_err());
+
+                // Wrong length
+                assert!(deserialize(ByteSeq::new($wrong_len)).is_err());
+            }
+
+            #[test]
+            fn test_point_arithmetic() {
+                // 3G computed independently
+                let g3 = (FieldElement::from_hex($g3_x), FieldElement::from_hex($g3_y));
+                let g = base_point();
+                let sum = point_add(point_double(g), g);
+                assert!(point_eq(sum, affine_to_projective(g3)));
+                assert_eq!(point_mul_base(Scalar::from_hex("03")).unwrap(), g3);
+                assert!(is_point_at_infinity(point_add(g, point_neg(g))));
+
+                // Variable-base, fixed-base and double-scalar multiplication agree.
+                let kat = &$ecdh_kat[0];
+                let sk = Scalar::from_hex(kat.sk);
+                let pk = point_mul_base(sk).unwrap();
+                assert_eq!(point_mul(sk, projective_to_affine(g)).unwrap(), pk);
+                let expected = point_add(
+                    affine_to_projective(point_mul_base(Scalar::from_hex("05")).unwrap()),
+                    affine_to_projective(point_mul(Scalar::from_hex("07"), pk).unwrap()),
+                );
+                assert_eq!(
+                    point_mul_add(Scalar::from_hex("05"), Scalar::from_hex("07"), pk).unwrap(),
+                    projective_to_affine(expected)
+                );
+            }
+        }
+    };
+}
+
+curve_tests!(
+    p384,
+    ecdh_kat: P384_ECDH_KAT,
+    n: "ffffffffffffffffffffffffffffffffffffffffffffffffc7634d81f4372ddf581a0db248b0a77aecec196accc52973",
+    // x = 1
+    non_square: "02000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001",
+    unreduced: "02fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffeffffffff0000000000000000ffffffff",
+    wrong_len: 96,
+    g3: (
+        "077a41d4606ffa1464793c7e5fdc7d98cb9d3910202dcd06bea4f240d3566da6b408bbae5026580d02d7e5c70500c831",
+        "c995f7ca0b0c42837d0bbe9602a9fc998520b41c85115aa5f7684c0edc111eacc24abd6be4b5d298b65f28600a2f1df1"
+    ),
+);
+
+curve_tests!(
+    p521,
+    ecdh_kat: P521_ECDH_KAT,
+    n: "01fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffa51868783bf2f966b7fcc0148f709a5d03bb5c9b8899c47aebb6fb71e91386409",
+    // x = 3
+    non_square: "02000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000003",
+    unreduced: "0201ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+    wrong_len: 132,
+    g3: (
+        "01a73d352443de29195dd91d6a64b5959479b52a6e5b123d9ab9e5ad7a112d7a8dd1ad3f164a3a4832051da6bd16b59fe21baeb490862c32ea05a5919d2ede37ad7d",
+        "013e9b03b97dfa62ddd9979f86c6cab814f2f1557fa82a9d0317d2f8ab1fa355ceec2e2dd4cf8dc575b02d5aced1dec3c70cf105c9bc93a590425f588ca1ee86c0e5"
+    ),
+);
@@ -0,0 +1,43 @@
+extern crate hacspec;
+use hacspec::prelude::*;
+
+extern crate hacspecs;
+use hacspecs::{chacha20::*, poly1305::*, xchacha20poly1305, xchacha20poly1305::XIV};
+
+// https://tools.ietf.org/html/draft-irtf-cfrg-xchacha-03#section-2.2.1
+#[test]
+fn test_hchacha() {
+    let key = Key::from("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f");
+    let iv = HChaChaIV::from("000000090000004a0000000031415927");
+    let expected = Key::from("82413b4227b27bfed30e42508a877d73a0f9e4d58a74a853c12ec41326d3ecdc");
+    let subkey = hchacha(key, iv);
+    assert_bytes_eq!(expected, subkey);
+}
+
+// https://tools.ietf.org/html/draft-irtf-cfrg-xchacha-03#appendix-A.3.1
+#[test]
+fn kat_test() {
+    let key = Key::from("808182838485868788898a8b8c8d8e8f909192939495969798999a9b9c9d9e9f");
+    let iv = XIV::from("404142434445464748494a4b4c4d4e4f5051525354555657");
+    let msg = ByteSeq::from("4c616469657320616e642047656e746c656d656e206f662074686520636c617373206f66202739393a204966204920636f756c64206f6666657220796f75206f6e6c79206f6e652074697020666f7220746865206675747572652c2073756e73637265656e20776f756c642062652069742e");
+    let aad = ByteSeq::from("50515253c0c1c2c3c4c5c6c7");
+    let exp_cipher = ByteSeq::from("bd6d179d3e83d43b9576579493c0e939572a1700252bfaccbed2902c21396cbb731c7f1b0b4aa6440bf3a82f4eda7e39ae64c6708c54c216cb96b72e1213b4522f8c9ba40db5d945b11b69b982c1bb9e3f3fac2bc369488f76b2383565d3fff921f9664c97637da9768812f615c68b13b52e");
+    let exp_mac = Tag::from("c0875924c1c7987947deafd8780acf49");
+
+    let (cipher, mac) = xchacha20poly1305::encrypt(key, iv, aad.clone(), msg.clone()).unwrap();
+    assert_bytes_eq!(exp_cipher, cipher);
+    assert_bytes_eq!(exp_mac, mac);
+    let decrypted_msg = xchacha20poly1305::decrypt(key, iv, aad, cipher, mac).unwrap();
+    assert_bytes_eq!(msg, decrypted_msg);
+}
+
+#[test]
+fn test_invalid_tag() {
+    let key = Key::random();
+    let iv = XIV::random();
+    let aad = ByteSeq::random(12);
+    let msg = ByteSeq::random(40);
+    let (cipher, _mac) = xchacha20poly1305::encrypt(key, iv, aad.clone(), msg).unwrap();
+    let decrypted_msg = xchacha20poly1305::decrypt(key, iv, aad, cipher, Tag::new());
+    assert!(decrypted_msg.is_err());
+}